@@ -1,20 +1,75 @@
-use std::fs::{OpenOptions};
-use std::io::{Write,ErrorKind};
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+#[path = "src/module.rs"]
+mod module;
+
+#[path = "src/vary.rs"]
+mod vary;
+
+// Lays out the learner's path for the test build: every koan in the manifest
+// that the learner's progress has reached, and always the first. Then fills
+// in the practice koans for the modules the learner has completed.
 fn main() {
-    let path = OpenOptions::new().create_new(true)
-        .write(true)
-        .open("src/path_to_enlightenment.rs");
-
-    match path {
-        Err(error) => {
-            match error.kind() {
-                ErrorKind::AlreadyExists => {},
-                _ => panic!("{}", error),
-            }
-        },
-        Ok(f) => {
-            write!(&f, "koan!(\"the_truth\");\n").unwrap();
-        },
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/koans.txt");
+    println!("cargo:rerun-if-changed=.progress");
+    println!("cargo:rerun-if-changed=src/templates");
+
+    let koans = lines(&root.join("src").join("koans.txt"));
+    let progress = lines(&root.join(".progress"));
+    let reached: Vec<String> = progress
+        .iter()
+        .filter_map(|line| line.split_whitespace().next().map(|name| name.to_string()))
+        .collect();
+
+    let mut path = File::create(out_dir.join("path_to_enlightenment.rs")).unwrap();
+    for (index, koan) in koans.iter().enumerate() {
+        if index == 0 || reached.contains(koan) {
+            writeln!(path, "koan!({}, \"{}\");", module::ident(koan), koan).unwrap();
+        }
+    }
+
+    // The seed is recorded as `practice: seed=N` when the first round begins,
+    // and changed each time a round is finished. Until then, one is chosen
+    // from the clock, for the runner to record.
+    let seed = progress
+        .iter()
+        .filter_map(|line| line.strip_prefix("practice: seed="))
+        .find_map(|seed| seed.trim().parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(1)
+        });
+    let completed: Vec<&str> = progress
+        .iter()
+        .filter(|line| line.split_whitespace().any(|field| field.starts_with("completed=")))
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+
+    let mut practice = File::create(out_dir.join("practice.rs")).unwrap();
+    writeln!(practice, "const SEED: u64 = {};", seed).unwrap();
+    writeln!(practice, "const ROUND: &[(&str, &str)] = &[").unwrap();
+    for koan in koans.iter().filter(|koan| completed.contains(&&koan[..])) {
+        let template = root.join("src").join("templates").join(format!("{}.rs", koan));
+        if let Ok(template) = fs::read_to_string(template) {
+            writeln!(practice, "    ({:?}, {:?}),", koan, vary::fill(&template, seed)).unwrap();
+        }
+    }
+    writeln!(practice, "];").unwrap();
+}
+
+fn lines(file: &Path) -> Vec<String> {
+    match File::open(file) {
+        Ok(file) => BufReader::new(file).lines().map(|line| line.unwrap()).collect(),
+        Err(_) => Vec::new(),
     }
 }
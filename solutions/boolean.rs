@@ -0,0 +1,34 @@
+// doc: book/ch03-02-data-types.html
+// doc: std/primitive.bool.html
+// doc: std/cmp/trait.PartialEq.html
+// summary: Booleans are true or false, and == compares values, not where
+//     they are kept.
+// reflect: Why are two strings with the same contents equal?
+// reflect: What does a type need in order to be compared with ==?
+
+// Booleans can have two values, true or false.
+// Two equal values will return true when compared with the == operator
+#[test]
+fn truth() {
+    koan_assert!("A value is always equal to itself.", true == true);
+}
+
+// Likewise, two unequal values will return false when compared with ==
+// The != operator can be used to return true for an inequality
+#[test]
+fn falsehood() {
+    koan_assert!("Two different values are not equal.", false != true);
+}
+
+// Strings can also be compared and will return a boolean
+#[test]
+fn string_equality() {
+    koan_assert!("Strings are equal when their contents are.", "Stuff" == "Stuff");
+}
+
+// Integers can be compared as long as they are of the same type
+#[test]
+fn int_equality() {
+    let num: i8 = 5;
+    koan_assert!("Integers are equal when their values are.", num == 5);
+}
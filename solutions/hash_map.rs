@@ -0,0 +1,134 @@
+// doc: book/ch08-03-hash-maps.html
+// doc: std/collections/struct.HashMap.html
+// summary: A HashMap stores values by key, and get returns an Option
+//     because a key may be absent.
+// reflect: What happens to the old value when a key is inserted twice?
+// reflect: Why is the order in which a HashMap is iterated not the order of
+//     insertion?
+
+use std::collections::HashMap;
+
+// A HashMap is a data structure that contains key-value pairs
+#[test]
+fn simple_hash_map() {
+    let mut hm = HashMap::new();
+    hm.insert("first", 1);
+    hm.insert("second", 2);
+    koan_assert_eq!(2, hm.len(), "A HashMap holds one entry for each key inserted.");
+}
+
+// You can access the values of a HashMap using the correlating key
+#[test]
+fn hash_map_get() {
+    let mut map = HashMap::new();
+    map.insert("Rust", "https://www.rust-lang.org/");
+    map.insert("Ruby", "https://www.ruby-lang.org/");
+    koan_assert_eq!(
+        map.get("Rust"),
+        Some(&"https://www.rust-lang.org/"),
+        "get looks up a value by its key."
+    );
+}
+
+// Attempting to retrieve a key that doesn't exist will return a None option
+#[test]
+fn its_not_there() {
+    let map: HashMap<&str, &str> = HashMap::new();
+    koan_assert_eq!(None, map.get("Rust"), "get returns None for a key that is not there.");
+}
+
+// Instead of the get() method, values can also be retrieved using []
+#[test]
+fn brackets() {
+    let mut map = HashMap::new();
+    map.insert("iPhone", "Apple");
+    map.insert("Galaxy", "Samsung");
+    koan_assert_eq!(map[&"iPhone"], "Apple", "A HashMap can be indexed by a key that is there.");
+    koan_assert_eq!(map[&"Galaxy"], "Samsung", "A HashMap can be indexed by a key that is there.");
+}
+
+// Keys in HashMaps will always be unique
+#[test]
+fn duplicate_key() {
+    let mut hm = HashMap::new();
+    hm.insert("Harry Potter", "Sorcerer's Stone");
+    hm.insert("Harry Potter", "Goblet of Fire");
+    koan_assert_eq!(
+        hm[&"Harry Potter"],
+        "Goblet of Fire",
+        "Inserting a key again replaces its value."
+    );
+}
+
+// A HashMap's values, however, do not have this constraint
+#[test]
+fn duplicate_values() {
+    let mut hm = HashMap::new();
+    hm.insert("Sorcerer's Stone", "Harry Potter");
+    hm.insert("Goblet of Fire", "Harry Potter");
+    koan_assert_eq!(
+        hm[&"Sorcerer's Stone"],
+        hm[&"Goblet of Fire"],
+        "Different keys may hold the same value."
+    );
+}
+
+// You can create an iterator of all of the keys in a HashMap
+#[test]
+fn just_the_keys() {
+    let mut map = HashMap::new();
+    map.insert("Episode IV", "A New Hope");
+    map.insert("Episode V", "Empire Strikes Back");
+    map.insert("Episode VI", "Return of the Jedi");
+    let episodes = vec!["Episode IV", "Episode V", "Episode VI"];
+    for episode in map.keys() {
+        koan_assert!("keys iterates over every key in the map.", episodes.contains(episode));
+    }
+}
+
+// You can do the same for the values in a HashMap
+#[test]
+fn just_the_values() {
+    let mut map = HashMap::new();
+    map.insert("One", "Fish");
+    map.insert("Two", "Fish");
+    map.insert("Red", "Fish");
+    map.insert("Blue", "Fish");
+    for num in map.values() {
+        koan_assert_eq!(&"Fish", num, "values iterates over every value in the map.");
+    }
+}
+
+// You can also iterate through all of the key value pairs together
+#[test]
+fn iterating() {
+    let mut map = HashMap::new();
+    map.insert(1, 1);
+    map.insert(2, 4);
+    map.insert(3, 9);
+    for (key, value) in map.iter() {
+        koan_assert_eq!(&(key * key), value, "iter yields each key alongside its value.");
+    }
+}
+
+// Rather than calling .iter() on the HashMap, you can also iterate over a reference to it
+#[test]
+fn iterating_2() {
+    let mut map = HashMap::new();
+    map.insert(1, 1);
+    map.insert(2, 4);
+    map.insert(3, 9);
+    for (key, value) in &map {
+        koan_assert_eq!(&(key * key), value, "iter yields each key alongside its value.");
+    }
+}
+
+// If we no longer need the content of a HashMap, it can be cleared and reused
+#[test]
+fn clearing() {
+    let mut map = HashMap::new();
+    map.insert("chairs", 30);
+    map.insert("tables", 8);
+    map.clear();
+    koan_assert_eq!(map.get("chairs"), None, "clear removes every entry from the map.");
+}
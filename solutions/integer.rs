@@ -0,0 +1,71 @@
+// doc: book/ch03-02-data-types.html
+// doc: std/primitive.u8.html
+// summary: Integers come in signed and unsigned widths, each with its own
+//     range, and are immutable unless declared mut.
+// reflect: Why can a u8 not go below zero, and what does Rust do if you
+//     try?
+// reflect: How do you choose between i32, u8 and usize?
+
+// As the name implies, unsigned integers (u8, u16, u32, u64) cannot be negative
+#[test]
+fn unsigned_ints() {
+    koan_assert!("An unsigned integer's minimum value is zero.", u8::min_value() == 0);
+}
+
+// Unsigned integers can be reduced only as far as their minimum value of 0
+#[test]
+fn sub_unsigned_int() {
+    let mut num: u8 = 10;
+    num -= 10;
+    koan_assert!("An unsigned integer can be reduced only as far as zero.", num == u8::min_value());
+}
+
+// Signed integers(i8, i16, i32, i64), on the other hand, can be negative
+#[test]
+fn signed_ints() {
+    koan_assert!("A signed integer's minimum value lies below zero.", i8::min_value() < 0);
+}
+
+// Signed integers can be reduced below zero, as far as their minimum value.
+// hint: The maximum magnitude for a negative integer is greater than
+// that of a positive integer
+#[test]
+fn sub_signed_int() {
+    let mut num: i8 = 0;
+    let negative: i8 = -128;
+    num += negative;
+    koan_assert!(
+        "A signed integer can go below zero, as far as its minimum.",
+        num == i8::min_value()
+    );
+}
+
+// Addition of positive integers works much the same for signed and unsigned numbers
+#[test]
+fn add_numbers() {
+    let mut sig: i8 = 0;
+    let mut unsig: u8 = 0;
+    sig += 127;
+    unsig += 255;
+    koan_assert!(
+        "Adding reaches an integer's maximum value just as it would any other.",
+        sig == i8::max_value() && unsig == u8::max_value()
+    );
+}
+
+// Like any variable in Rust, integers are immutable unless declared otherwise
+#[test]
+fn mutating_ints() {
+    let mut num: i8 = 1;
+    num += 2;
+    koan_assert!("A mutable integer can be changed.", num == 3);
+}
+
+// While regular immutable variables cannot be changed, mutable versions of them can be
+#[test]
+fn referencing_values() {
+    let num: i8 = 1;
+    let mut mut_num = num;
+    mut_num += 1;
+    koan_assert!("A copy of an integer can change without changing the original.", num != mut_num);
+}
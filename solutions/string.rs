@@ -0,0 +1,128 @@
+// doc: book/ch08-02-strings.html
+// doc: std/string/struct.String.html
+// doc: std/primitive.str.html
+// summary: Rust has two kinds of string: the borrowed slice &str and the
+//     owned, growable String.
+// reflect: When should a function take a &str rather than a String?
+// reflect: Why are strings sliced by bytes rather than characters, and what
+//     can go wrong?
+
+// Rust has string literals called string slices (&str)
+// String slices are static and cannot be mutated
+// They are functionally a pointer with an unchangeable length
+#[test]
+fn string_literals() {
+    let string_slice = "Hello World";
+    koan_assert!(
+        "A string slice is equal to a literal of the same text.",
+        string_slice == "Hello World"
+    );
+}
+
+// A String is a heap-allocated string in Rust.
+// It is mutable and growable
+#[test]
+fn growable_strings() {
+    let mut string = String::new();
+    string.push_str("Hello");
+    string.push_str(" World");
+    koan_assert!("push_str appends to a String.", string == "Hello World");
+}
+
+// A string slice can be converted to a String using to_string
+// But since converting &str to a String involved memory allocation
+// It is cheaper to keep them as &strs if possible
+#[test]
+fn growable_string_literals() {
+    let mut mutable = "Foo".to_string();
+    mutable.push_str("Bar");
+    koan_assert!(
+        "to_string turns a string slice into a String that can grow.",
+        mutable == "FooBar"
+    );
+}
+
+// A String can be coerced into a slice by prefacing it with a &
+#[test]
+fn string_to_slice() {
+    let string = "Can't stop me now".to_string();
+    let slice: &str = &string;
+    koan_assert!("A String can be borrowed as a slice of itself.", slice == "Can't stop me now");
+}
+
+// You can concat a &str to a String at the end
+#[test]
+fn strings_with_strs() {
+    let hello = "Hello ".to_string();
+    let world = "World";
+
+    koan_assert!("A &str can be added to the end of a String.", hello + world == "Hello World");
+}
+
+// But two Strings require a & to coerce the second String
+#[test]
+fn strings_with_strings() {
+    let hello = "Hello ".to_string();
+    let world = "World!".to_string();
+
+    let hello_world = hello + &world;
+    koan_assert!(
+        "Adding one String to another needs the second to be borrowed.",
+        hello_world == "Hello World!"
+    )
+}
+
+// Strings cannot be indexed as they are UTF-8 encoded
+// Some UTF-8 characters can be multiple bytes long
+// But you can access the string as chars and iterate from there using nth
+#[test]
+fn using_chars() {
+    let string = "Anybody hungry?";
+    koan_assert!(
+        "chars iterates over a string's characters.",
+        string.chars().nth(2) == "y".chars().nth(0)
+    );
+}
+
+// You can get a slice of a string using slicing syntax
+#[test]
+fn slicing_the_string() {
+    let string = "Boom";
+    koan_assert!("A string is sliced by bytes.", &string[0..2] == "Bo");
+}
+
+// However these are BYTE offsets not character offsets -
+// rust will panic if you try and slice within a character boundary
+#[test]
+#[should_panic]
+fn slicing_the_byte() {
+    let dog = "忠犬ハチ公";
+    let slice = &dog[0..2];
+}
+
+// You can truncate Strings
+#[test]
+fn truncate_string() {
+    let mut string = String::from("Hello World!");
+    string.truncate(5);
+    koan_assert!("truncate shortens a String to a given length.", string == "Hello");
+}
+
+// You can pop Strings
+#[test]
+fn pop_string() {
+    let mut string = String::from("Hello");
+    string.pop();
+    koan_assert!("pop removes the last character of a String.", string == "Hell");
+}
+
+// You can insert and remove from a String (at byte position)
+#[test]
+fn insert_and_remove_into_string() {
+    let mut string = String::from("Hello");
+    string.insert(2, 'e');
+    koan_assert!("insert places a character at a byte index.", string == "Heello");
+
+    string.remove(2);
+    koan_assert!("remove takes out the character at a byte index.", string == "Hello");
+}
@@ -0,0 +1,117 @@
+// doc: book/ch05-01-defining-structs.html
+// doc: book/ch05-03-method-syntax.html
+// summary: Structs gather related data into one named type, and are
+//     immutable unless bound with mut.
+// reflect: Why is mutability decided by the binding rather than by each
+//     field?
+// reflect: When is struct update syntax, ..other, worth reaching for?
+
+// Structs are a convenient way to wrap up related data into one neatly packaged structure.
+// Let's consider the example of a Struct that allows us to package together data about a person.
+#[test]
+fn our_first_struct() {
+    struct Person {
+        name: &'static str,
+        age: u32,
+    }
+
+    let jim = Person {
+        name: "Jim",
+        age: 57,
+    };
+
+    koan_assert_eq!("Jim", jim.name, "A struct's fields are read with a dot.");
+    koan_assert_eq!(57, jim.age, "A struct's fields are read with a dot.");
+}
+
+// Let's try another example
+#[test]
+fn one_more_struct() {
+    struct Movie {
+        title: &'static str,
+        runtime: u32,
+    }
+
+    let movie = Movie {
+        title: "Star Wars",
+        runtime: 121,
+    };
+
+    koan_assert_eq!("Star Wars", movie.title, "A struct's fields are read with a dot.");
+    koan_assert_eq!(movie.runtime, 121, "A struct's fields are read with a dot.");
+}
+
+// Structs, like anything in Rust, are immutable by default.
+// If we create a mutable instance of a Struct, we can reassign its attributes.
+#[test]
+fn mutable_structs() {
+    struct Language {
+        version: &'static str,
+    }
+
+    let mut rust = Language { version: "1.3.0" };
+
+    rust.version = "1.4.0";
+
+    koan_assert_eq!(rust.version, "1.4.0", "The fields of a mutable struct can be changed.");
+}
+
+// We can also decide to temporarily allow a Struct to be mutable
+#[test]
+fn revoking_mutability() {
+    struct Language {
+        version: &'static str,
+    }
+
+    let mut rust = Language { version: "1.3.0" };
+
+    rust.version = "1.4.0";
+
+    koan_assert_eq!(
+        rust.version,
+        "1.4.0",
+        "A struct can be changed only while it is bound mutably."
+    );
+
+    let rust = rust;
+
+    let mut rust = rust;
+
+    rust.version = "1.5.0";
+
+    koan_assert_eq!(
+        rust.version,
+        "1.5.0",
+        "A struct can be changed only while it is bound mutably."
+    );
+}
+
+// There may be cases where you want to create a new instance of a Struct
+// that is only slightly different from an existing one
+#[test]
+fn dot_merging() {
+    struct Account {
+        holder: &'static str,
+        account_number: &'static str,
+        balance: f64,
+    }
+
+    let broke = Account {
+        holder: "Morgan Stanley",
+        account_number: "00021948523756312",
+        balance: 0.00,
+    };
+
+    let rich = Account { balance: 1000000.00, ..broke };
+
+    koan_assert_eq!(
+        "Morgan Stanley",
+        rich.holder,
+        "Struct update syntax takes the remaining fields from another struct."
+    );
+    koan_assert_eq!(
+        1000000.00,
+        rich.balance,
+        "Struct update syntax takes the remaining fields from another struct."
+    );
+}
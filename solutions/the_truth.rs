@@ -0,0 +1,13 @@
+// doc: book/ch11-01-writing-tests.html
+// doc: std/macro.assert.html
+// summary: Every koan is a test, and a test passes when what it asserts is
+//     true.
+// reflect: What does assert! do when its condition is false?
+// reflect: Why might a test that cannot fail teach you nothing?
+
+// Every koan is a test that asserts something is true. Fill in the blank,
+// and the path opens before you.
+#[test]
+fn the_truth() {
+    koan_assert!("The path begins with what is true.", true)
+}
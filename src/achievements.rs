@@ -0,0 +1,78 @@
+use error::PathError;
+use koan;
+use locale;
+use progress::{self, Achievement, Progress};
+
+// A milestone on the path, known by how it is recorded in .progress. Its
+// title and description are kept in the message catalogue.
+pub struct Milestone {
+    pub id: &'static str,
+}
+
+impl Milestone {
+    pub fn title(&self) -> String {
+        locale::text(&format!("{}-title", self.id), &[])
+    }
+
+    pub fn description(&self) -> String {
+        locale::text(&format!("{}-description", self.id), &[])
+    }
+}
+
+pub const MILESTONES: [Milestone; 7] = [
+    Milestone { id: "first_step" },
+    Milestone { id: "first_module" },
+    Milestone { id: "unaided" },
+    Milestone { id: "ownership" },
+    Milestone { id: "streak_3" },
+    Milestone { id: "streak_7" },
+    Milestone { id: "enlightenment" },
+];
+
+// Awards the learner every milestone newly reached, recording it in their
+// progress, and returns them so that they may be announced.
+pub fn award(progress: &mut Progress, path: &[String]) -> Result<Vec<&'static Milestone>, PathError> {
+    let mut reached = Vec::new();
+    for milestone in MILESTONES.iter() {
+        if !progress.has_earned(milestone.id) && has_reached(milestone.id, progress, path)? {
+            reached.push(milestone);
+        }
+    }
+
+    for milestone in &reached {
+        progress.achievements.push(Achievement {
+            id: milestone.id.to_string(),
+            earned: progress::now(),
+        });
+    }
+    Ok(reached)
+}
+
+fn has_reached(id: &str, progress: &Progress, path: &[String]) -> Result<bool, PathError> {
+    let completed = progress.completed();
+    let reached = match id {
+        "first_step" => !completed.is_empty(),
+        "first_module" => {
+            let mut reached = false;
+            for entry in &completed {
+                reached = reached || koan::koans_in(&koan::read_source(&entry.name)?).len() > 1;
+            }
+            reached
+        }
+        "unaided" => {
+            let mut reached = false;
+            for entry in completed.iter().filter(|entry| entry.hints == 0) {
+                reached = reached || !koan::tagged(&koan::read_source(&entry.name)?, "hint").is_empty();
+            }
+            reached
+        }
+        "ownership" => completed.iter().any(|entry| entry.name == "ownership"),
+        "streak_3" => progress.streak.days >= 3,
+        "streak_7" => progress.streak.days >= 7,
+        "enlightenment" => path
+            .iter()
+            .all(|name| completed.iter().any(|entry| entry.name == *name)),
+        _ => false,
+    };
+    Ok(reached)
+}
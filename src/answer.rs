@@ -0,0 +1,85 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+use error::PathError;
+use koan;
+use locale;
+use output::{paint, Style};
+use paths;
+
+// Fills the blanks on the first line of a koan that has any, asking the
+// learner for each in turn, and writes the answers back into the koan.
+// Returns false if there was nothing to fill or the learner gave no answer.
+pub fn fill_a_blank(module: &str) -> Result<bool, PathError> {
+    let source = koan::read_source(module)?;
+    let number = match koan::first_blank(&source) {
+        Some(number) => number,
+        None => {
+            println!("{}", text!("no-blanks", module = paint(Style::Highlight, module)));
+            return Ok(false);
+        }
+    };
+
+    if let Some(koan) = koan::koans_in(&source).iter().find(|koan| koan.contains_line(number)) {
+        for line in locale::comment(module, koan).lines() {
+            println!("{}", paint(Style::Comment, line));
+        }
+        println!("{} ({}.rs:{})", paint(Style::Highlight, &koan.name), module, number);
+    }
+
+    let mut line = source.lines().nth(number - 1).unwrap_or_default().to_string();
+    while let Some(&start) = koan::blanks(&line).first() {
+        println!("{:>4} | {}", number, mark_blanks(&line));
+        let answer = ask(&format!("{} ", text!("fill-the-blank")))?;
+        if answer.is_empty() {
+            println!("{}", text!("blank-remains"));
+            return Ok(false);
+        }
+        line.replace_range(start..start + 2, &answer);
+    }
+    println!("{:>4} | {}", number, line);
+
+    // Keep the rest of the file, line endings and all, as it was
+    let filled: String = source
+        .split_inclusive('\n')
+        .enumerate()
+        .map(|(index, original)| {
+            if index + 1 == number {
+                let ending = &original[original.trim_end_matches(['\r', '\n']).len()..];
+                format!("{}{}", line, ending)
+            } else {
+                original.to_string()
+            }
+        })
+        .collect();
+
+    let path = paths::koan(module);
+    fs::write(&path, filled).map_err(|error| PathError::io(&format!("write {}", path.display()), error))?;
+    Ok(true)
+}
+
+fn mark_blanks(line: &str) -> String {
+    let mut marked = String::new();
+    let mut rest = 0;
+    for start in koan::blanks(line) {
+        marked.push_str(&line[rest..start]);
+        marked.push_str(&paint(Style::Highlight, "__"));
+        rest = start + 2;
+    }
+    marked.push_str(&line[rest..]);
+    marked
+}
+
+fn ask(question: &str) -> Result<String, PathError> {
+    print!("{}", question);
+    io::stdout()
+        .flush()
+        .map_err(|error| PathError::io("ask for an answer", error))?;
+
+    let mut answer = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .map_err(|error| PathError::io("read your answer", error))?;
+    Ok(answer.trim().to_string())
+}
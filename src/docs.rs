@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+// Where the documentation lives online, for those without a local copy.
+const ONLINE: &str = "https://doc.rust-lang.org/stable/";
+
+// Gathers the "doc:" comments from a koan file. Each names a page of the
+// documentation that `rustup doc` installs, relative to its root, such as
+// book/ch10-02-traits.html or std/vec/struct.Vec.html.
+pub fn references(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("//"))
+        .filter_map(|comment| comment.trim().strip_prefix("doc:"))
+        .map(|reference| reference.trim().to_string())
+        .filter(|reference| !reference.is_empty())
+        .collect()
+}
+
+// The root of the documentation installed alongside the toolchain, if the
+// rust-docs component is installed.
+pub fn local_root() -> Option<PathBuf> {
+    let output = Command::new("rustc").arg("--print").arg("sysroot").output().ok()?;
+    let sysroot = String::from_utf8(output.stdout).ok()?;
+    let root = PathBuf::from(sysroot.trim())
+        .join("share")
+        .join("doc")
+        .join("rust")
+        .join("html");
+    if root.is_dir() {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+// Where to read a reference: the local copy if there is one, or online.
+pub fn resolve(local_root: Option<&PathBuf>, reference: &str) -> String {
+    let page = reference.split('#').next().unwrap_or(reference);
+    match local_root {
+        Some(root) if root.join(page).is_file() => {
+            format!("file://{}", root.join(reference).display())
+        }
+        _ => format!("{}{}", ONLINE, reference),
+    }
+}
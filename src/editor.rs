@@ -0,0 +1,49 @@
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+use error::PathError;
+
+// Opens a file in the learner's editor, as named by $VISUAL or $EDITOR, with
+// the cursor on the given line.
+pub fn open(file: &Path, line: usize) -> Result<(), PathError> {
+    let editor = env::var("VISUAL")
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+        .or_else(|| env::var("EDITOR").ok().filter(|editor| !editor.trim().is_empty()))
+        .ok_or_else(|| {
+            PathError::Usage(text!("no-editor", file = format!("{}:{}", file.display(), line)))
+        })?;
+
+    // The editor may come with arguments of its own, such as `code --wait`
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or_default();
+    let mut command = Command::new(program);
+    command.args(words).args(at_line(program, file, line));
+
+    let status = command
+        .status()
+        .map_err(|error| PathError::io(&format!("start {}", program), error))?;
+    if !status.success() {
+        eprintln!("{}", text!("editor-closed", editor = program, status = status.to_string()));
+    }
+    Ok(())
+}
+
+// How each editor is asked to open a file at a line.
+fn at_line(program: &str, file: &Path, line: usize) -> Vec<String> {
+    let name = Path::new(program)
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .unwrap_or(program);
+    let file = file.display().to_string();
+
+    match name {
+        "vi" | "vim" | "nvim" | "gvim" | "mvim" | "nano" | "emacs" | "emacsclient" | "micro" | "kak" | "joe"
+        | "mg" | "ne" | "gedit" | "kate" => vec![format!("+{}", line), file],
+        "code" | "code-insiders" | "codium" | "cursor" => vec!["-g".to_string(), format!("{}:{}", file, line)],
+        "subl" | "sublime_text" | "zed" | "hx" | "helix" | "atom" | "mate" => vec![format!("{}:{}", file, line)],
+        "idea" | "clion" | "rustrover" => vec!["--line".to_string(), line.to_string(), file],
+        _ => vec![file],
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+use std::io::{self, ErrorKind};
+
+// Everything that can keep the runner from walking the path. Each kind of
+// trouble leaves with its own exit code, so scripts can tell them apart.
+pub enum PathError {
+    // The koans were walked, and some were found amiss, as the message says.
+    KoansAmiss(String),
+    // The command line asked for something the runner does not know.
+    Usage(String),
+    // There is no Cargo.toml where the runner was started, nor above it.
+    WrongDirectory,
+    // A crate was found, but src/koans.txt was not.
+    MissingManifest,
+    // cargo could not be started.
+    CargoNotFound,
+    // A record of the learner's progress could not be understood.
+    CorruptProgress {
+        file: String,
+        line: usize,
+        reason: String,
+    },
+    // Any other failure to read or write, with what was being attempted.
+    Io { action: String, error: io::Error },
+}
+
+impl PathError {
+    pub fn io(action: &str, error: io::Error) -> PathError {
+        PathError::Io {
+            action: action.to_string(),
+            error,
+        }
+    }
+
+    // The failure to start or wait on cargo.
+    pub fn cargo(error: io::Error) -> PathError {
+        if error.kind() == ErrorKind::NotFound {
+            PathError::CargoNotFound
+        } else {
+            PathError::io("run cargo", error)
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match *self {
+            PathError::KoansAmiss(_) => 1,
+            PathError::Usage(_) => 2,
+            PathError::WrongDirectory => 3,
+            PathError::MissingManifest => 4,
+            PathError::CargoNotFound => 5,
+            PathError::CorruptProgress { .. } => 6,
+            PathError::Io { .. } => 7,
+        }
+    }
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PathError::KoansAmiss(ref message) => write!(f, "{}", message),
+            PathError::Usage(ref message) => write!(f, "{}", message),
+            PathError::WrongDirectory => write!(f, "{}", text!("wrong-directory")),
+            PathError::MissingManifest => write!(f, "{}", text!("missing-manifest")),
+            PathError::CargoNotFound => write!(f, "{}", text!("cargo-not-found")),
+            PathError::CorruptProgress {
+                ref file,
+                line,
+                ref reason,
+            } => write!(
+                f,
+                "{}",
+                text!("corrupt-progress", line = line.to_string(), file = file, reason = reason)
+            ),
+            PathError::Io {
+                ref action,
+                ref error,
+            } => write!(f, "{}", text!("io", action = action, error = error.to_string())),
+        }
+    }
+}
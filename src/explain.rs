@@ -0,0 +1,112 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::process::Command;
+
+use error::PathError;
+use paths;
+
+// A compiler error, as cargo reported it.
+pub struct Diagnostic {
+    // The error code, such as E0382, if the error has one.
+    pub code: Option<String>,
+    // The whole of the diagnostic, from its headline to the blank line that
+    // ends it.
+    pub text: String,
+}
+
+// Finds the errors the compiler raised against a koan file, in the order it
+// raised them.
+pub fn diagnostics(said: &str, module: &str) -> Vec<Diagnostic> {
+    let site = format!("src/koans/{}.rs:", module);
+    let mut diagnostics = Vec::new();
+    let mut current: Option<Diagnostic> = None;
+
+    for line in said.lines() {
+        if line.starts_with("error") {
+            diagnostics.extend(current.take());
+            current = Some(Diagnostic {
+                code: error_code(line),
+                text: String::new(),
+            });
+        }
+        if line.trim().is_empty() {
+            diagnostics.extend(current.take());
+        }
+        if let Some(ref mut diagnostic) = current {
+            diagnostic.text.push_str(line);
+            diagnostic.text.push('\n');
+        }
+    }
+    diagnostics.extend(current);
+
+    diagnostics.retain(|diagnostic| diagnostic.text.contains(&site));
+    diagnostics
+}
+
+// The code of an error headline such as `error[E0382]: borrow of moved value`.
+fn error_code(line: &str) -> Option<String> {
+    let code = line.strip_prefix("error[")?.split(']').next()?;
+    if is_error_code(code) {
+        Some(code.to_string())
+    } else {
+        None
+    }
+}
+
+pub fn is_error_code(code: &str) -> bool {
+    code.len() == 5 && code.starts_with('E') && code[1..].chars().all(|c| c.is_ascii_digit())
+}
+
+// What `rustc --explain` has to say of an error code.
+pub fn rustc_explain(code: &str) -> Result<String, PathError> {
+    let output = Command::new("rustc")
+        .arg("--explain")
+        .arg(code)
+        .output()
+        .map_err(|error| PathError::io(&format!("run rustc --explain {}", code), error))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(PathError::Usage(text!("no-such-error-code", code = code)))
+    }
+}
+
+// What the koans have to say of an error code, if they have anything to say.
+pub fn note(code: &str) -> Result<Option<String>, PathError> {
+    let path = paths::note(code);
+    match fs::read_to_string(&path) {
+        Ok(note) => Ok(Some(note)),
+        Err(ref error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(PathError::io(&format!("read {}", path.display()), error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagnostics_are_those_raised_against_the_koan() {
+        let said = "\
+   Compiling koans v0.1.0
+error[E0382]: borrow of moved value: `a`
+  --> src/koans/ownership.rs:12:20
+   |
+12 |     println!(\"{}\", a);
+   |                    ^ value borrowed here after move
+
+error: unused variable
+ --> src/koans/vec.rs:3:9
+
+error[E0425]: cannot find value `__` in this scope
+  --> src/koans/ownership.rs:20:5
+";
+        let diagnostics = diagnostics(said, "ownership");
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].code.as_deref(), Some("E0382"));
+        assert!(diagnostics[0].text.starts_with("error[E0382]"));
+        assert_eq!(diagnostics[0].text.lines().count(), 5);
+        assert_eq!(diagnostics[1].code.as_deref(), Some("E0425"));
+    }
+}
@@ -0,0 +1,142 @@
+// Support for the koans themselves, compiled only into the test build.
+//
+// koan_assert! and koan_assert_eq! behave like assert! and assert_eq!, but
+// carry a lesson. When one fails it panics with a Failure, which the hook
+// installed here presents to the learner in place of the usual panic message.
+
+use std::fmt::Debug;
+use std::panic::{self, PanicHookInfo};
+use std::sync::Once;
+use std::thread;
+
+// Asserts that a koan's condition holds, teaching the lesson if it does not.
+// The lesson comes first so that the condition is passed on token by token
+// and parsed where the koan writes it: a blank in place of an operator, as in
+// `true __ true`, is then reported at the koan's own line.
+#[allow(unused_macros)]
+macro_rules! koan_assert {
+    ($lesson:expr, $($cond:tt)+) => {
+        if !($($cond)+) {
+            ::harness::fail(::harness::Failure::new(
+                $lesson,
+                stringify!($($cond)+),
+                "false".to_string(),
+                "true".to_string(),
+                file!(),
+                line!(),
+            ));
+        }
+    };
+}
+
+// Asserts that the learner's answer, on the left, equals what the path
+// expects, on the right, teaching the lesson if it does not.
+#[allow(unused_macros)]
+macro_rules! koan_assert_eq {
+    ($answer:expr, $expected:expr, $lesson:expr) => {
+        match (&$answer, &$expected) {
+            (answer, expected) => {
+                if !(*answer == *expected) {
+                    ::harness::fail(::harness::Failure::answered(
+                        $lesson,
+                        concat!(stringify!($answer), " == ", stringify!($expected)),
+                        answer,
+                        expected,
+                        file!(),
+                        line!(),
+                    ));
+                }
+            }
+        }
+    };
+}
+
+const ZEN: [&str; 6] = [
+    "The obstacle is the path.",
+    "Before enlightenment, chop wood, carry water. After enlightenment, chop wood, carry water.",
+    "A journey of a thousand miles begins beneath one's feet.",
+    "When the student is ready, the compiler will appear.",
+    "Empty your mind, and the answer will fill it.",
+    "To know and not to do is not yet to know.",
+];
+
+// A koan assertion that did not hold.
+pub struct Failure {
+    pub koan: String,
+    pub lesson: &'static str,
+    pub assertion: &'static str,
+    pub answer: String,
+    pub expected: String,
+    pub file: &'static str,
+    pub line: u32,
+}
+
+impl Failure {
+    pub fn new(
+        lesson: &'static str,
+        assertion: &'static str,
+        answer: String,
+        expected: String,
+        file: &'static str,
+        line: u32,
+    ) -> Failure {
+        // libtest runs each koan on a thread named for it
+        let koan = thread::current()
+            .name()
+            .map(|name| name.rsplit("::").next().unwrap_or(name).to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        Failure {
+            koan,
+            lesson,
+            assertion,
+            answer,
+            expected,
+            file,
+            line,
+        }
+    }
+
+    pub fn answered<A: Debug, E: Debug>(
+        lesson: &'static str,
+        assertion: &'static str,
+        answer: &A,
+        expected: &E,
+        file: &'static str,
+        line: u32,
+    ) -> Failure {
+        Failure::new(lesson, assertion, format!("{:?}", answer), format!("{:?}", expected), file, line)
+    }
+
+    fn zen(&self) -> &'static str {
+        let seed = self.koan.bytes().fold(0usize, |seed, byte| seed.wrapping_mul(31).wrapping_add(byte as usize));
+        ZEN[seed % ZEN.len()]
+    }
+}
+
+static HOOK: Once = Once::new();
+
+// libtest gives the harness no chance to run before the koans do, so the
+// hook is installed by the first koan assertion to fail.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info: &PanicHookInfo| match info.payload().downcast_ref::<Failure>() {
+            Some(failure) => {
+                eprintln!(
+                    "The koan {} has not yet been solved: `{}` ({}:{})",
+                    failure.koan, failure.assertion, failure.file, failure.line
+                );
+                eprintln!("    Your answer: {} — the path expected: {}", failure.answer, failure.expected);
+                eprintln!("    {}", failure.lesson);
+                eprintln!("    {}", failure.zen());
+            }
+            None => default_hook(info),
+        }));
+    });
+}
+
+pub fn fail(failure: Failure) -> ! {
+    install_hook();
+    panic::panic_any(failure)
+}
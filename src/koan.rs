@@ -0,0 +1,489 @@
+use std::fs::File;
+use std::io::Read;
+
+use error::PathError;
+use module;
+use paths;
+
+// A single #[test] within a koan file.
+pub struct Koan {
+    pub name: String,
+    // The comment directly above the test, which explains what it teaches.
+    pub comment: String,
+    // The test's attributes and function, as written.
+    pub source: String,
+    // The line of the file on which the test begins, counting from 1.
+    pub line: usize,
+}
+
+impl Koan {
+    pub fn contains_line(&self, line: usize) -> bool {
+        line >= self.line && line < self.line + self.source.lines().count()
+    }
+}
+
+pub fn read_source(module: &str) -> Result<String, PathError> {
+    let path = paths::koan(module);
+    let mut source = String::new();
+    File::open(&path)
+        .and_then(|mut file| file.read_to_string(&mut source))
+        .map_err(|error| PathError::io(&format!("read {}", path.display()), error))?;
+    Ok(source)
+}
+
+// Finds the top level #[test] functions of a koan file, in order.
+pub fn koans_in(source: &str) -> Vec<Koan> {
+    split(source).1
+}
+
+// Splits a koan file into everything outside of its tests, such as the `use`
+// declarations they share, and the tests themselves.
+pub fn split(source: &str) -> (String, Vec<Koan>) {
+    let mut prelude = String::new();
+    let mut koans = Vec::new();
+    let mut depth = 0;
+    let mut comment: Vec<&str> = Vec::new();
+    // The test being read, if any, until its name is known
+    let mut current: Option<(Koan, Option<String>)> = None;
+    // The attributes above the item about to be read, with the line the first
+    // is on, which #[test] may be among in whatever order they are written
+    let mut attributes: Vec<&str> = Vec::new();
+    let mut attributes_line = 0;
+
+    for (index, line) in source.lines().enumerate() {
+        let code = strip_comment(line);
+        let trimmed = code.trim();
+
+        if depth == 0 && current.is_none() && trimmed.starts_with("#[") {
+            if attributes.is_empty() {
+                attributes_line = index + 1;
+            }
+            attributes.push(line);
+            continue;
+        }
+
+        if attributes.iter().any(|attribute| strip_comment(attribute).trim() == "#[test]") {
+            let mut koan = Koan {
+                name: String::new(),
+                comment: comment.join("\n"),
+                source: String::new(),
+                line: attributes_line,
+            };
+            for attribute in attributes.drain(..) {
+                koan.source.push_str(attribute);
+                koan.source.push('\n');
+            }
+            current = Some((koan, None));
+        } else {
+            for attribute in attributes.drain(..) {
+                prelude.push_str(attribute);
+                prelude.push('\n');
+            }
+        }
+
+        depth += braces(code);
+
+        let finished = match current {
+            Some((ref mut koan, ref mut name)) => {
+                koan.source.push_str(line);
+                koan.source.push('\n');
+                if name.is_none() {
+                    *name = fn_name(trimmed);
+                }
+                name.is_some() && depth == 0 && code.contains('}')
+            }
+            None => {
+                prelude.push_str(line);
+                prelude.push('\n');
+                false
+            }
+        };
+
+        if current.is_none() && depth == 0 && trimmed.is_empty() && !line.trim().is_empty() {
+            comment.push(line.trim().trim_start_matches('/').trim());
+        } else {
+            comment.clear();
+        }
+
+        if finished {
+            if let Some((mut koan, Some(name))) = current.take() {
+                koan.name = name;
+                koans.push(koan);
+            }
+        }
+    }
+    for attribute in attributes {
+        prelude.push_str(attribute);
+        prelude.push('\n');
+    }
+
+    (prelude, koans)
+}
+
+// A koan file with every test but one blanked out, line for line, so that
+// what the compiler says of the one left still points into the file.
+pub fn alone(source: &str, koans: &[Koan], name: &str) -> String {
+    source
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let others = koans.iter().filter(|koan| koan.name != name);
+            if others.into_iter().any(|koan| koan.contains_line(index + 1)) {
+                "\n".to_string()
+            } else {
+                format!("{}\n", line)
+            }
+        })
+        .collect()
+}
+
+// The koan the learner is working on within a module: the one cargo says is
+// failing, or else the first with a blank left in it.
+pub fn at_hand(koans: Vec<Koan>, said: &str, module: &str) -> Option<Koan> {
+    let error_line = error_line(said, module);
+    let failing = |koan: &Koan| {
+        failed(said, &test_name(module, &koan.name)) || error_line.is_some_and(|number| koan.contains_line(number))
+    };
+
+    let position = koans
+        .iter()
+        .position(failing)
+        .or_else(|| koans.iter().position(|koan| koan.source.contains("__")))
+        .unwrap_or(0);
+    koans.into_iter().nth(position)
+}
+
+// Whether cargo says a test failed, as `cargo test` does with
+// `test name - should panic ... FAILED` or `cargo test -q` with
+// `name --- FAILED`.
+fn failed(said: &str, name: &str) -> bool {
+    said.lines().any(|line| {
+        let line = line.strip_prefix("test ").unwrap_or(line);
+        line.strip_prefix(name).is_some_and(|rest| {
+            let rest = rest.strip_prefix(" - should panic").unwrap_or(rest);
+            rest == " ... FAILED" || rest == " --- FAILED"
+        })
+    })
+}
+
+// The name the test harness knows a koan by, such as
+// path_to_enlightenment::vec::insert.
+pub fn test_name(module: &str, koan: &str) -> String {
+    format!("path_to_enlightenment::{}::{}", module::ident(module), koan)
+}
+
+// Gathers the comments in a koan file that carry a tag, such as "hint:".
+// Each runs on over any comment lines that directly follow it.
+pub fn tagged(source: &str, tag: &str) -> Vec<String> {
+    let tag = format!("{}:", tag);
+    let mut found: Vec<String> = Vec::new();
+    let mut in_tagged = false;
+
+    for line in source.lines() {
+        let line = line.trim();
+        if !line.starts_with("//") {
+            in_tagged = false;
+            continue;
+        }
+        let comment = line.trim_start_matches('/').trim();
+        if let Some(start) = comment.find(&tag) {
+            found.push(comment[start + tag.len()..].trim().to_string());
+            in_tagged = true;
+        } else if in_tagged && !comment.is_empty() && !is_tagged(comment) {
+            let text = found.last_mut().unwrap();
+            text.push(' ');
+            text.push_str(comment);
+        } else {
+            in_tagged = false;
+        }
+    }
+
+    found
+}
+
+// Whether a comment begins with a tag of its own, such as "doc:".
+fn is_tagged(comment: &str) -> bool {
+    comment
+        .split_whitespace()
+        .next()
+        .and_then(|word| word.strip_suffix(':'))
+        .is_some_and(|tag| !tag.is_empty() && tag.chars().all(|c| c.is_ascii_lowercase()))
+}
+
+// The byte offsets of the `__` blanks left in a line of a koan, outside of
+// comments and not part of a longer name.
+pub fn blanks(line: &str) -> Vec<usize> {
+    let code = strip_comment(line);
+    let is_name = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+    code.match_indices("__")
+        .map(|(start, _)| start)
+        .filter(|&start| !is_name(code[..start].chars().next_back()) && !is_name(code[start + 2..].chars().next()))
+        .collect()
+}
+
+// The first line of a koan file with a blank left in it, counting from 1.
+pub fn first_blank(source: &str) -> Option<usize> {
+    source
+        .lines()
+        .position(|line| !blanks(line).is_empty())
+        .map(|index| index + 1)
+}
+
+// The first line of a koan file that cargo's output points at, from a
+// compiler diagnostic such as `--> src/koans/array.rs:6:12` or a panic.
+pub fn error_line(said: &str, module: &str) -> Option<usize> {
+    let site = format!("src/koans/{}.rs:", module);
+    said.lines().find_map(|line| {
+        let start = line.find(&site)? + site.len();
+        line[start..].split(|c: char| !c.is_ascii_digit()).next()?.parse().ok()
+    })
+}
+
+fn fn_name(line: &str) -> Option<String> {
+    let rest = line.trim_start_matches("pub ").strip_prefix("fn ")?;
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    match comment_start(line) {
+        Some(start) => &line[..start],
+        None => line,
+    }
+}
+
+// The byte offset of a // comment that is not inside a string literal.
+fn comment_start(line: &str) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut in_string = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if in_string => i += 1,
+            b'"' => in_string = !in_string,
+            b'/' if !in_string && bytes.get(i + 1) == Some(&b'/') => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+// The change in brace depth over a line of code, ignoring braces in string
+// and character literals.
+fn braces(code: &str) -> i32 {
+    let chars: Vec<char> = code.chars().collect();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if in_string => i += 1,
+            '"' => in_string = !in_string,
+            '\'' if !in_string => {
+                // Skip a character literal, but not a lifetime such as 'a
+                if chars.get(i + 1) == Some(&'\\') {
+                    i += 3;
+                    while i < chars.len() && chars[i] != '\'' {
+                        i += 1;
+                    }
+                } else if chars.get(i + 2) == Some(&'\'') {
+                    i += 2;
+                }
+            }
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    depth
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(source: &str) -> Vec<String> {
+        split(source).1.into_iter().map(|koan| koan.name).collect()
+    }
+
+    #[test]
+    fn split_keeps_the_prelude_apart_from_the_koans() {
+        let source = "\
+use std::collections::HashMap;
+
+// Keys are unique
+#[test]
+fn unique() {
+    assert!(__);
+}
+";
+        let (prelude, koans) = split(source);
+        assert_eq!(prelude, "use std::collections::HashMap;\n\n// Keys are unique\n");
+        assert_eq!(koans.len(), 1);
+        assert_eq!(koans[0].name, "unique");
+        assert_eq!(koans[0].comment, "Keys are unique");
+        assert_eq!(koans[0].line, 4);
+        assert_eq!(koans[0].source, "#[test]\nfn unique() {\n    assert!(__);\n}\n");
+    }
+
+    #[test]
+    fn split_reads_attributes_in_either_order() {
+        let source = "\
+// Panics after the test attribute
+#[test]
+#[should_panic]
+fn after() {
+    panic!();
+}
+
+// Panics before the test attribute
+#[should_panic]
+#[test]
+fn before() {
+    panic!();
+}
+";
+        let (prelude, koans) = split(source);
+        assert_eq!(prelude, "// Panics after the test attribute\n\n// Panics before the test attribute\n");
+        assert_eq!(koans.len(), 2);
+        for koan in &koans {
+            assert!(koan.source.contains("#[should_panic]\n"), "{}", koan.source);
+            assert!(koan.source.contains("#[test]\n"), "{}", koan.source);
+        }
+        assert_eq!(koans[1].name, "before");
+        assert_eq!(koans[1].comment, "Panics before the test attribute");
+        assert_eq!(koans[1].line, 9);
+    }
+
+    #[test]
+    fn split_leaves_attributes_of_other_items_in_the_prelude() {
+        let source = "\
+#[derive(Debug)]
+struct Monk;
+
+#[test]
+fn monk() {
+    assert!(__);
+}
+";
+        let (prelude, koans) = split(source);
+        assert_eq!(prelude, "#[derive(Debug)]\nstruct Monk;\n\n");
+        assert_eq!(koans[0].comment, "");
+    }
+
+    #[test]
+    fn split_is_not_misled_by_braces_in_literals() {
+        let source = "\
+#[test]
+fn braces() {
+    let open = '{';
+    let close = '}';
+    let escaped = '\\u{7d}';
+    let quoted = \"}\\\"}\";
+    assert_eq!(format!(\"{}{}\", open, close), __);
+}
+
+#[test]
+fn after_braces() {
+    assert!(__);
+}
+";
+        assert_eq!(names(source), ["braces", "after_braces"]);
+    }
+
+    #[test]
+    fn split_reads_nested_blocks_and_lifetimes() {
+        let source = "\
+#[test]
+fn nested() {
+    fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+        if x.len() > y.len() { x } else { y }
+    }
+    assert_eq!(longest(\"a\", \"bc\"), __);
+}
+
+#[test]
+fn next() {
+    assert!(__);
+}
+";
+        let koans = split(source).1;
+        assert_eq!(koans.len(), 2);
+        assert_eq!(koans[0].source.lines().count(), 7);
+        assert_eq!(koans[1].line, 9);
+    }
+
+    #[test]
+    fn braces_counts_only_code() {
+        assert_eq!(braces("fn main() {"), 1);
+        assert_eq!(braces("}"), -1);
+        assert_eq!(braces("let c = '{';"), 0);
+        assert_eq!(braces("let c = '\\u{7b}';"), 0);
+        assert_eq!(braces("let s = \"{ \\\" {\";"), 0);
+        assert_eq!(braces("fn f<'a>(s: &'a str) {"), 1);
+    }
+
+    #[test]
+    fn blanks_are_found_outside_names_and_comments() {
+        assert_eq!(blanks("    assert_eq!(__, 2);"), [15]);
+        assert_eq!(blanks("    let __x = y__ + __;"), [20]);
+        assert_eq!(blanks("    let x = __init__;"), Vec::<usize>::new());
+        assert_eq!(blanks("    assert!(true); // fill in the __"), Vec::<usize>::new());
+        assert_eq!(blanks("    assert_eq!(\"//\", __);"), [21]);
+    }
+
+    const PANICKING: &str = "\
+// Panics when it should not
+#[test]
+fn unwrap_none() {
+    let nothing: Option<u8> = None;
+    nothing.unwrap();
+}
+
+// Should panic, but does not
+#[test]
+#[should_panic]
+fn slicing() {
+    let word = \"zen\";
+    let _ = &word[0..1];
+}
+
+// Still has a blank
+#[test]
+fn blank() {
+    assert!(__);
+}
+";
+
+    #[test]
+    fn at_hand_is_the_koan_cargo_says_failed() {
+        let quiet = "path_to_enlightenment::vec::slicing --- FAILED\n";
+        let koan = at_hand(koans_in(PANICKING), quiet, "vec").unwrap();
+        assert_eq!(koan.name, "slicing");
+
+        let verbose = "test path_to_enlightenment::vec::slicing - should panic ... FAILED\n";
+        let koan = at_hand(koans_in(PANICKING), verbose, "vec").unwrap();
+        assert_eq!(koan.name, "slicing");
+
+        let verbose = "test path_to_enlightenment::vec::unwrap_none ... FAILED\n";
+        let koan = at_hand(koans_in(PANICKING), verbose, "vec").unwrap();
+        assert_eq!(koan.name, "unwrap_none");
+    }
+
+    #[test]
+    fn at_hand_is_otherwise_the_first_koan_with_a_blank() {
+        let koan = at_hand(koans_in(PANICKING), "path_to_enlightenment::vec::slices --- FAILED\n", "vec").unwrap();
+        assert_eq!(koan.name, "blank");
+    }
+}
@@ -1,4 +1,12 @@
+// doc: book/ch03-02-data-types.html
+// doc: std/ops/trait.Add.html
+// summary: Rust's arithmetic operators work on numbers of the same type,
+//     and the compiler checks that they do.
+// reflect: What would happen if you added an i32 to a u8?
+// reflect: Where else might you see the + operator at work?
+
+// The + operator adds two numbers together.
 #[test]
 fn addition() {
-    assert_eq!(1 + __, 2)
+    koan_assert_eq!(1 + __, 2, "One and one make two.")
 }
@@ -1,9 +1,19 @@
+// doc: book/ch03-02-data-types.html
+// doc: std/primitive.array.html
+// doc: std/iter/trait.Iterator.html
+// summary: Arrays have a fixed length known to the compiler, and iterators
+//     let you map, filter and walk over them without an index.
+// reflect: What happens at runtime when an index is out of bounds, and why
+//     does Rust not simply return nothing?
+// reflect: When would you reach for filter_map rather than filter followed
+//     by map?
+
 // The elements of an array can be accessed by their indices
 // arr[4]
 #[test]
 fn array_index() {
     let arr: [i32; 5] = [1, 2, 3, 4, 5];
-    assert!(arr[__] == 1);
+    koan_assert!("Arrays are indexed from zero.", arr[__] == 1);
 }
 
 // A new fixed size array can be created by declaring the type of its elements
@@ -12,7 +22,7 @@ fn array_index() {
 #[test]
 fn array_empty() {
     let arr: __;
-    assert!(arr.len() == 0);
+    koan_assert!("An array's length is part of its type.", arr.len() == 0);
 }
 
 // Attempting to access an array at an index that is
@@ -20,7 +30,7 @@ fn array_empty() {
 // that error in this example.
 #[test]
 #[should_panic]
-#[allow(const_err)]
+#[allow(unconditional_panic)]
 fn out_of_index() {
     let arr: [&'static str; 5] = ["rust", "is", "mostly", "for", "nerds"];
     arr[__];
@@ -32,7 +42,7 @@ fn out_of_index() {
 fn insert_at_index() {
     let mut arr: [u8; 5] = [0, 1, 2, 3, 4];
     __ = 0;
-    assert!(arr == [0, 1, 2, 3, 0]);
+    koan_assert!("Changing an element requires a mutable binding.", arr == [0, 1, 2, 3, 0]);
 }
 
 // Arrays can be iterated over.
@@ -40,9 +50,9 @@ fn insert_at_index() {
 fn array_iteration() {
     let arr: [u8; 3] = [3, 2, 1];
     let mut iterator = arr.iter();
-    assert!(iterator.next().unwrap() == &__);
-    assert!(iterator.next().unwrap() == &__);
-    assert!(iterator.next().unwrap() == &__);
+    koan_assert!("An iterator yields each element in order.", iterator.next().unwrap() == &__);
+    koan_assert!("An iterator yields each element in order.", iterator.next().unwrap() == &__);
+    koan_assert!("An iterator yields each element in order.", iterator.next().unwrap() == &__);
 }
 
 // Arrays can also be mutated during iteration
@@ -50,10 +60,10 @@ fn array_iteration() {
 fn array_map() {
     let arr: [u32; 4] = [2, 5, 7, 4];
     let mut iterator = arr.iter().map(__);
-    assert!(iterator.next() == Some(4));
-    assert!(iterator.next() == Some(10));
-    assert!(iterator.next() == Some(14));
-    assert!(iterator.next() == Some(8));
+    koan_assert!("map transforms each element as it is yielded.", iterator.next() == Some(4));
+    koan_assert!("map transforms each element as it is yielded.", iterator.next() == Some(10));
+    koan_assert!("map transforms each element as it is yielded.", iterator.next() == Some(14));
+    koan_assert!("map transforms each element as it is yielded.", iterator.next() == Some(8));
 }
 
 // You can filter an array for results that match a given condition
@@ -61,9 +71,9 @@ fn array_map() {
 fn array_filter() {
     let arr: [u16; 5] = [1, 2, 3, 4, 5];
     let mut iterator = arr.iter().filter(__);
-    assert!(iterator.next().unwrap() == &2);
-    assert!(iterator.next().unwrap() == &4);
-    assert!(iterator.next().is_none());
+    koan_assert!("filter keeps only the elements that match.", iterator.next().unwrap() == &2);
+    koan_assert!("filter keeps only the elements that match.", iterator.next().unwrap() == &4);
+    koan_assert!("An iterator yields None once it is spent.", iterator.next().is_none());
 }
 
 // Filter and map can be combined to do both at once
@@ -71,9 +81,9 @@ fn array_filter() {
 fn array_filter_map() {
     let arr: [u8; 5] = [2, 1, 2, 1, 2];
     let mut iterator = arr.iter().filter_map(|&x| if x == 1 { Some(__) } else { None });
-    assert!(iterator.next() == Some(3));
-    assert!(iterator.next() == Some(3));
-    assert!(iterator.next().is_none());
+    koan_assert!("filter_map filters and transforms in one step.", iterator.next() == Some(3));
+    koan_assert!("filter_map filters and transforms in one step.", iterator.next() == Some(3));
+    koan_assert!("An iterator yields None once it is spent.", iterator.next().is_none());
 }
 
 // This can be used for more complex logic as well
@@ -86,9 +96,9 @@ fn complex_array_filter_map() {
         } else {
             None
         });
-    assert!(iterator.next().unwrap() == __);
-    assert!(iterator.next().unwrap() == __);
-    assert!(iterator.next().is_none());
+    koan_assert!("filter_map keeps only what it returns Some of.", iterator.next().unwrap() == __);
+    koan_assert!("filter_map keeps only what it returns Some of.", iterator.next().unwrap() == __);
+    koan_assert!("An iterator yields None once it is spent.", iterator.next().is_none());
 }
 
 // Arrays can also be iterated through using a for loop
@@ -97,7 +107,7 @@ fn for_loops() {
     let arr: [u64; 3] = [1, 2, 3];
     let mut y: u64 = 1;
     for x in &arr {
-        assert!(*x == y);
+        koan_assert!("A for loop visits each element in turn.", *x == y);
         __
     }
 }
@@ -111,5 +121,8 @@ fn for_loops_two() {
         __
     }
     println!("{:?}", sentence);
-    assert!(sentence == "I love Rust".to_string());
+    koan_assert!(
+        "A for loop can build something up as it goes.",
+        sentence == "I love Rust".to_string()
+    );
 }
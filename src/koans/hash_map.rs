@@ -1,3 +1,11 @@
+// doc: book/ch08-03-hash-maps.html
+// doc: std/collections/struct.HashMap.html
+// summary: A HashMap stores values by key, and get returns an Option
+//     because a key may be absent.
+// reflect: What happens to the old value when a key is inserted twice?
+// reflect: Why is the order in which a HashMap is iterated not the order of
+//     insertion?
+
 use std::collections::HashMap;
 
 // A HashMap is a data structure that contains key-value pairs
@@ -6,7 +14,7 @@ fn simple_hash_map() {
     let mut hm = HashMap::new();
     hm.insert("first", 1);
     hm.insert("second", 2);
-    assert_eq!(hm.len(), __);
+    koan_assert_eq!(__, hm.len(), "A HashMap holds one entry for each key inserted.");
 }
 
 // You can access the values of a HashMap using the correlating key
@@ -15,14 +23,18 @@ fn hash_map_get() {
     let mut map = HashMap::new();
     map.insert("Rust", "https://www.rust-lang.org/");
     map.insert("Ruby", "https://www.ruby-lang.org/");
-    assert_eq!(map.get(__), Some(&"https://www.rust-lang.org/"));
+    koan_assert_eq!(
+        map.get(__),
+        Some(&"https://www.rust-lang.org/"),
+        "get looks up a value by its key."
+    );
 }
 
 // Attempting to retrieve a key that doesn't exist will return a None option
 #[test]
 fn its_not_there() {
     let map: HashMap<&str, &str> = HashMap::new();
-    assert_eq!(map.get("Rust"), __);
+    koan_assert_eq!(__, map.get("Rust"), "get returns None for a key that is not there.");
 }
 
 // Instead of the get() method, values can also be retrieved using []
@@ -31,8 +43,8 @@ fn brackets() {
     let mut map = HashMap::new();
     map.insert("iPhone", "Apple");
     map.insert("Galaxy", "Samsung");
-    assert_eq!(map[&"iPhone"], "Apple");
-    assert_eq!(__, "Samsung");
+    koan_assert_eq!(map[&"iPhone"], "Apple", "A HashMap can be indexed by a key that is there.");
+    koan_assert_eq!(__, "Samsung", "A HashMap can be indexed by a key that is there.");
 }
 
 // Keys in HashMaps will always be unique
@@ -41,14 +53,22 @@ fn duplicate_key() {
     let mut hm = HashMap::new();
     hm.insert("Harry Potter", "Sorcerer's Stone");
     hm.insert("Harry Potter", "Goblet of Fire");
-    assert_eq!(hm[&"Harry Potter"], "Sorcerer's Stone");
+    koan_assert_eq!(
+        hm[&"Harry Potter"],
+        "Sorcerer's Stone",
+        "Inserting a key again replaces its value."
+    );
 }
 
 // A HashMap's values, however, do not have this constraint
 #[test]
 fn duplicate_values() {
     let mut hm = HashMap::new();
-    assert_eq!(hm[&"Sorcerer's Stone"], hm[&"Goblet of Fire"]);
+    koan_assert_eq!(
+        hm[&"Sorcerer's Stone"],
+        hm[&"Goblet of Fire"],
+        "Different keys may hold the same value."
+    );
 }
 
 // You can create an iterator of all of the keys in a HashMap
@@ -60,7 +80,7 @@ fn just_the_keys() {
     map.insert("Episode VI", "Return of the Jedi");
     let episodes = vec![__];
     for episode in map.keys() {
-        assert!(episodes.contains(episode));
+        koan_assert!("keys iterates over every key in the map.", episodes.contains(episode));
     }
 }
 
@@ -73,7 +93,7 @@ fn just_the_values() {
     map.insert("Red", "Fish");
     map.insert("Blue", "Fish");
     for num in map.values() {
-        assert_eq!(num, __);
+        koan_assert_eq!(__, num, "values iterates over every value in the map.");
     }
 }
 
@@ -85,7 +105,7 @@ fn iterating() {
     map.insert(2, 4);
     map.insert(3, 9);
     for (key, value) in map.iter() {
-        assert_eq!(&(__), value);
+        koan_assert_eq!(&(__), value, "iter yields each key alongside its value.");
     }
 }
 
@@ -97,7 +117,7 @@ fn iterating_2() {
     map.insert(2, 4);
     map.insert(3, 9);
     for __ in &map {
-        assert_eq!(__, value);
+        koan_assert_eq!(__, value, "iter yields each key alongside its value.");
     }
 }
 
@@ -107,5 +127,5 @@ fn clearing() {
     let mut map = HashMap::new();
     map.insert("chairs", 30);
     map.insert("tables", 8);
-    assert_eq!(map.get("chairs"), None);
+    koan_assert_eq!(map.get("chairs"), None, "clear removes every entry from the map.");
 }
@@ -1,7 +1,15 @@
+// doc: book/ch03-02-data-types.html
+// doc: std/primitive.u8.html
+// summary: Integers come in signed and unsigned widths, each with its own
+//     range, and are immutable unless declared mut.
+// reflect: Why can a u8 not go below zero, and what does Rust do if you
+//     try?
+// reflect: How do you choose between i32, u8 and usize?
+
 // As the name implies, unsigned integers (u8, u16, u32, u64) cannot be negative
 #[test]
 fn unsigned_ints() {
-    assert!(u8::min_value() == __);
+    koan_assert!("An unsigned integer's minimum value is zero.", u8::min_value() == __);
 }
 
 // Unsigned integers can be reduced only as far as their minimum value of 0
@@ -9,13 +17,13 @@ fn unsigned_ints() {
 fn sub_unsigned_int() {
     let mut num: u8 = 10;
     num -= 10;
-    assert!(num __ u8::min_value());
+    koan_assert!("An unsigned integer can be reduced only as far as zero.", num __ u8::min_value());
 }
 
 // Signed integers(i8, i16, i32, i64), on the other hand, can be negative
 #[test]
 fn signed_ints() {
-    assert!(i8::min_value() __ 0);
+    koan_assert!("A signed integer's minimum value lies below zero.", i8::min_value() __ 0);
 }
 
 // Signed integers can be reduced below zero, as far as their minimum value.
@@ -26,7 +34,10 @@ fn sub_signed_int() {
     let mut num: i8 = 0;
     let negative: i8 = __;
     num += negative;
-    assert!(num == i8::min_value());
+    koan_assert!(
+        "A signed integer can go below zero, as far as its minimum.",
+        num == i8::min_value()
+    );
 }
 
 // Addition of positive integers works much the same for signed and unsigned numbers
@@ -36,7 +47,10 @@ fn add_numbers() {
     let mut unsig: u8 = 0;
     sig += __;
     unsig += __;
-    assert!(sig == i8::max_value() && unsig == u8::max_value());
+    koan_assert!(
+        "Adding reaches an integer's maximum value just as it would any other.",
+        sig == i8::max_value() && unsig == u8::max_value()
+    );
 }
 
 // Like any variable in Rust, integers are immutable unless declared otherwise
@@ -44,7 +58,7 @@ fn add_numbers() {
 fn mutating_ints() {
     let __ num: i8 = 1;
     num += 2;
-    assert!(num == 3);
+    koan_assert!("A mutable integer can be changed.", num == 3);
 }
 
 // While regular immutable variables cannot be changed, mutable versions of them can be
@@ -53,5 +67,5 @@ fn referencing_values() {
     let num: i8 = 1;
     __ = num;
     mut_num += 1;
-    assert!(num != mut_num);
+    koan_assert!("A copy of an integer can change without changing the original.", num != mut_num);
 }
@@ -1,3 +1,13 @@
+// doc: book/ch04-01-what-is-ownership.html
+// doc: book/ch04-02-references-and-borrowing.html
+// summary: Every value has one owner. Moving a value transfers ownership,
+//     copying duplicates simple values, and borrowing lends access
+//     without giving ownership away.
+// reflect: Why can you use a &str after assigning it to another binding,
+//     but not a String?
+// reflect: Why can there be many shared borrows of a value but only one
+//     mutable borrow?
+
 // A solid understanding of Rust's ownership rules is vital to working effectively in Rust.
 // When a value is bound to a variable, that variable is said to have ownership of it.
 // We'll go through several examples to help explain how ownership works.
@@ -10,7 +20,7 @@ fn owning_a_value() {
         let x = 10;
     }
     assign_a_value();
-    assert_eq!(x, 10);
+    koan_assert_eq!(x, 10, "A value lives only as long as the variable that owns it.");
 }
 
 // When a variable goes out of scope, it is cleaned up by Rust and its memory is deallocated.
@@ -22,7 +32,7 @@ fn owning_a_value_2() {
         let y = &x;
     }
     assign_a_value();
-    assert_eq!(y, &10);
+    koan_assert_eq!(y, &10, "A reference cannot outlive the value it borrows.");
 }
 
 // Another example of a variables ownership ending is the concept of "moving" the value.
@@ -31,7 +41,11 @@ fn owning_a_value_2() {
 fn moving_a_value() {
     let name = String::from("Chris");
     let first_name = name;
-    assert_eq!(name, "Chris".to_string());
+    koan_assert_eq!(
+        name,
+        "Chris".to_string(),
+        "A value that has been moved can no longer be used."
+    );
 }
 
 // Some confusion can arise with moving values, because certain data types aren't moved.
@@ -41,7 +55,7 @@ fn moving_a_value() {
 fn copying_a_value() {
     let name = "Chris";
     let first_name = name;
-    assert_eq!(name, __);
+    koan_assert_eq!(__, name, "A &str is copied rather than moved.");
 }
 
 // The same will happen with integer types like i32. These types contain no pointers to other data.
@@ -50,7 +64,7 @@ fn copying_a_value() {
 fn copying_a_value_2() {
     let num: i32 = 12;
     let x = num;
-    assert_eq!(x, __);
+    koan_assert_eq!(__, x, "Integers are copied rather than moved.");
 }
 
 // Now that we've explored the difference between what types get moved and what types get copied,
@@ -60,7 +74,7 @@ fn copying_a_value_2() {
 fn rebinding_a_vec() {
     let list = vec!["Rust", "Go", "C++"];
     let languages = list;
-    assert_eq!(list[0], "Rust");
+    koan_assert_eq!(list[0], "Rust", "A Vec is moved, not copied, when it is bound again.");
 }
 
 // Now that you've learned a bit about ownership in Rust, it's time to look at borrowing.
@@ -71,8 +85,8 @@ fn rebinding_a_vec() {
 fn simple_borrowing() {
     let name = String::from("Chris");
     let first_name = &name;
-    assert_eq!(__, "Chris".to_string());
-    assert_eq!(__, &"Chris".to_string());
+    koan_assert_eq!(__, "Chris".to_string(), "Borrowing leaves the value with its owner.");
+    koan_assert_eq!(__, &"Chris".to_string(), "A reference is compared with another reference.");
 }
 // Unlike our earlier example, name has not been deallocated,
 // because first_name has created a reference to it.
@@ -84,9 +98,9 @@ fn mutable_borrowing() {
     {
         let new_count = &count;
         *new_count += 1;
-        assert_eq!(new_count, &11);
+        koan_assert_eq!(new_count, &11, "Only a mutable borrow may change the value it borrows.");
     }
-    assert_eq!(count, 11);
+    koan_assert_eq!(count, 11, "The owner sees changes made through a mutable borrow.");
 }
 
 // Borrowing can also be used to pass values through functions without needing to rebind them.
@@ -101,7 +115,11 @@ fn borrowing_through_functions() {
 
     insert_next_number(vector);
 
-    assert_eq!(vector, vec![1, 2, 3, 4]);
+    koan_assert_eq!(
+        vector,
+        vec![1, 2, 3, 4],
+        "A function can borrow a value without taking ownership of it."
+    );
 }
 
 // Up until now, we've talked about things being cleaned up or deallocated,
@@ -118,8 +136,8 @@ fn implicit_lifetime() {
 
     let sum = add(x, y);
 
-    assert_eq!(sum, 20);
-    assert_eq!(a, 10);
+    koan_assert_eq!(sum, 20, "Values passed to a function are copied in, and the result returned.");
+    koan_assert_eq!(a, 10, "A function's parameters do not exist outside it.");
 }
 
 // Let's look at a similar function, but with references passed as arguments instead
@@ -137,6 +155,6 @@ fn explicit_lifetime() {
 
     let max = max(&x, &y);
 
-    assert_eq!(max, 20);
+    koan_assert_eq!(max, 20, "A lifetime ties a returned reference to its arguments.");
 }
 // Here we're saying that the i32 we return will have a lifetime equal to that of the function max
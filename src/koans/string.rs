@@ -1,10 +1,19 @@
+// doc: book/ch08-02-strings.html
+// doc: std/string/struct.String.html
+// doc: std/primitive.str.html
+// summary: Rust has two kinds of string: the borrowed slice &str and the
+//     owned, growable String.
+// reflect: When should a function take a &str rather than a String?
+// reflect: Why are strings sliced by bytes rather than characters, and what
+//     can go wrong?
+
 // Rust has string literals called string slices (&str)
 // String slices are static and cannot be mutated
 // They are functionally a pointer with an unchangeable length
 #[test]
 fn string_literals() {
     let string_slice = "Hello World";
-    assert!(string_slice == __);
+    koan_assert!("A string slice is equal to a literal of the same text.", string_slice == __);
 }
 
 // A String is a heap-allocated string in Rust.
@@ -14,7 +23,7 @@ fn growable_strings() {
     let mut string = String::new();
     string.push_str("Hello");
     string.push_str(" World");
-    assert!(string == __);
+    koan_assert!("push_str appends to a String.", string == __);
 }
 
 // A string slice can be converted to a String using to_string
@@ -24,7 +33,7 @@ fn growable_strings() {
 fn growable_string_literals() {
     let mut mutable = "Foo".to_string();
     mutable.push_str("Bar");
-    assert!(mutable == __);
+    koan_assert!("to_string turns a string slice into a String that can grow.", mutable == __);
 }
 
 // A String can be coerced into a slice by prefacing it with a &
@@ -32,7 +41,7 @@ fn growable_string_literals() {
 fn string_to_slice() {
     let string = "Can't stop me now".to_string();
     let slice: &str = __;
-    assert!(slice == "Can't stop me now");
+    koan_assert!("A String can be borrowed as a slice of itself.", slice == "Can't stop me now");
 }
 
 // You can concat a &str to a String at the end
@@ -41,7 +50,7 @@ fn strings_with_strs() {
     let hello = "Hello ".to_string();
     let world = "World";
 
-    assert!(hello + world == __);
+    koan_assert!("A &str can be added to the end of a String.", hello + world == __);
 }
 
 // But two Strings require a & to coerce the second String
@@ -51,7 +60,10 @@ fn strings_with_strings() {
     let world = "World!".to_string();
 
     let hello_world = __ + __;
-    assert!(hello_world == "Hello World!")
+    koan_assert!(
+        "Adding one String to another needs the second to be borrowed.",
+        hello_world == "Hello World!"
+    )
 }
 
 // Strings cannot be indexed as they are UTF-8 encoded
@@ -60,14 +72,17 @@ fn strings_with_strings() {
 #[test]
 fn using_chars() {
     let string = "Anybody hungry?";
-    assert!(string.chars().nth(2) == __.chars().nth(0));
+    koan_assert!(
+        "chars iterates over a string's characters.",
+        string.chars().nth(2) == __.chars().nth(0)
+    );
 }
 
 // You can get a slice of a string using slicing syntax
 #[test]
 fn slicing_the_string() {
     let string = "Boom";
-    assert!(&string[0..2] == __);
+    koan_assert!("A string is sliced by bytes.", &string[0..2] == __);
 }
 
 // However these are BYTE offsets not character offsets -
@@ -76,7 +91,7 @@ fn slicing_the_string() {
 #[should_panic]
 fn slicing_the_byte() {
     let dog = "忠犬ハチ公";
-    let slice = &dog[0..2];
+    let slice = &dog[0..__];
 }
 
 // You can truncate Strings
@@ -84,7 +99,7 @@ fn slicing_the_byte() {
 fn truncate_string() {
     let mut string = String::from("Hello World!");
     string.truncate(5);
-    assert!(string == __);
+    koan_assert!("truncate shortens a String to a given length.", string == __);
 }
 
 // You can pop Strings
@@ -92,7 +107,7 @@ fn truncate_string() {
 fn pop_string() {
     let mut string = String::from("Hello");
     string.pop();
-    assert!(string == __);
+    koan_assert!("pop removes the last character of a String.", string == __);
 }
 
 // You can insert and remove from a String (at byte position)
@@ -100,8 +115,8 @@ fn pop_string() {
 fn insert_and_remove_into_string() {
     let mut string = String::from("Hello");
     string.insert(2, 'e');
-    assert!(string == __);
+    koan_assert!("insert places a character at a byte index.", string == __);
 
     string.remove(2);
-    assert!(string == __);
+    koan_assert!("remove takes out the character at a byte index.", string == __);
 }
@@ -1,3 +1,11 @@
+// doc: book/ch05-01-defining-structs.html
+// doc: book/ch05-03-method-syntax.html
+// summary: Structs gather related data into one named type, and are
+//     immutable unless bound with mut.
+// reflect: Why is mutability decided by the binding rather than by each
+//     field?
+// reflect: When is struct update syntax, ..other, worth reaching for?
+
 // Structs are a convenient way to wrap up related data into one neatly packaged structure.
 // Let's consider the example of a Struct that allows us to package together data about a person.
 #[test]
@@ -12,8 +20,8 @@ fn our_first_struct() {
         age: 57,
     };
 
-    assert_eq!(jim.name, __);
-    assert_eq!(jim.age, __);
+    koan_assert_eq!(__, jim.name, "A struct's fields are read with a dot.");
+    koan_assert_eq!(__, jim.age, "A struct's fields are read with a dot.");
 }
 
 // Let's try another example
@@ -29,8 +37,8 @@ fn one_more_struct() {
         runtime: __,
     };
 
-    assert_eq!(movie.title, __);
-    assert_eq!(movie.runtime, 121);
+    koan_assert_eq!(__, movie.title, "A struct's fields are read with a dot.");
+    koan_assert_eq!(movie.runtime, 121, "A struct's fields are read with a dot.");
 }
 
 // Structs, like anything in Rust, are immutable by default.
@@ -45,7 +53,7 @@ fn mutable_structs() {
 
     __ = "1.4.0";
 
-    assert_eq!(rust.version, "1.4.0");
+    koan_assert_eq!(rust.version, "1.4.0", "The fields of a mutable struct can be changed.");
 }
 
 // We can also decide to temporarily allow a Struct to be mutable
@@ -59,13 +67,21 @@ fn revoking_mutability() {
 
     rust.version = "1.4.0";
 
-    assert_eq!(rust.version, "1.4.0");
+    koan_assert_eq!(
+        rust.version,
+        "1.4.0",
+        "A struct can be changed only while it is bound mutably."
+    );
 
     let rust = rust;
 
     rust.version = "1.5.0";
 
-    assert_eq!(rust.version, "1.5.0");
+    koan_assert_eq!(
+        rust.version,
+        "1.5.0",
+        "A struct can be changed only while it is bound mutably."
+    );
 }
 
 // There may be cases where you want to create a new instance of a Struct
@@ -86,6 +102,14 @@ fn dot_merging() {
 
     let rich = Account { balance: 1000000.00, ..broke };
 
-    assert_eq!(rich.holder, __);
-    assert_eq!(rich.balance, __);
+    koan_assert_eq!(
+        __,
+        rich.holder,
+        "Struct update syntax takes the remaining fields from another struct."
+    );
+    koan_assert_eq!(
+        __,
+        rich.balance,
+        "Struct update syntax takes the remaining fields from another struct."
+    );
 }
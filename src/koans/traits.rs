@@ -1,7 +1,13 @@
+// doc: book/ch10-02-traits.html
+// summary: Traits describe behaviour that types share, and bounds let
+//     generic code demand that behaviour.
+// reflect: What does a default method on a trait save the implementor from?
+// reflect: Why must a type implement PartialOrd before it may implement a
+//     trait that extends it?
+
 // Traits in Rust are a way of guaranteeing particular functionality for a type.
 // They let the compiler know that a type is capable of necessary functions to
-// help it ensure safety. For more information, check the book:
-// https://doc.rust-lang.org/nightly/book/traits.html
+// help it ensure safety.
 #[test]
 fn implementing_traits() {
     struct Person {
@@ -30,7 +36,11 @@ fn implementing_traits() {
     // full_name(). In order to guarantee this, it is cast to receive any
     // argument type that has implemented the HasName trait.
     fn assert_full_name<T: HasName>(person: T) {
-        assert_eq!(person.full_name(), "Chris Cerami");
+        koan_assert_eq!(
+            person.full_name(),
+            "Chris Cerami",
+            "A function bound by a trait may call that trait's methods."
+        );
     }
 
     assert_full_name(person);
@@ -65,7 +75,11 @@ fn implementing_traits2() {
     };
 
     fn test_level_up<T: HasLevel>(character: &mut T) {
-        assert_eq!(character.level_up(), 3);
+        koan_assert_eq!(
+            character.level_up(),
+            3,
+            "A type must implement every method of a trait it implements."
+        );
     }
 
     test_level_up(&mut durz);
@@ -78,8 +92,8 @@ fn creating_traits() {
     let num_two: u16 = 4;
 
     fn asserts<T: IsEvenOrOdd>(x: T, y: T) {
-        assert!(!x.is_even());
-        assert!(y.is_even());
+        koan_assert!("A trait can be implemented for a type you did not write.", !x.is_even());
+        koan_assert!("A trait can be implemented for a type you did not write.", y.is_even());
     }
 
     asserts(num_one, num_two);
@@ -105,7 +119,10 @@ fn trait_constraints_on_structs() {
         latest_version: "1.5.0",
     };
 
-    assert!(rust.is_stable());
+    koan_assert!(
+        "A generic type must be bound by the traits its methods rely on.",
+        rust.is_stable()
+    );
 }
 
 // There is an alternate syntax for placing trait bounds on a function, the
@@ -126,8 +143,8 @@ fn where_clause() {
     }
 
     fn asserts<T>(x: T, y: T) {
-        assert!(!x.is_even());
-        assert!(y.is_even());
+        koan_assert!("A where clause bounds generic types just as a colon does.", !x.is_even());
+        koan_assert!("A where clause bounds generic types just as a colon does.", y.is_even());
     }
 
     asserts(num_one, num_two);
@@ -154,8 +171,8 @@ fn default_functions() {
     }
 
     fn asserts<T: IsEvenOrOdd>(x: T, y: T) {
-        assert!(x.is_odd());
-        assert!(y.is_even());
+        koan_assert!("A trait may provide a default method built on its others.", x.is_odd());
+        koan_assert!("A trait may provide a default method built on its others.", y.is_even());
     }
 
     asserts(num_one, num_two);
@@ -193,5 +210,5 @@ fn inheritance() {
     let a = Bawks { thingy: 5.0 };
     let b = Bawks { thingy: 7.0 };
 
-    assert!(a.is_before(&b));
+    koan_assert!("A trait may require that its implementors implement another.", a.is_before(&b));
 }
@@ -1,15 +1,23 @@
+// doc: book/ch08-01-vectors.html
+// doc: std/vec/struct.Vec.html
+// summary: A Vec is a growable array that owns its elements, with methods
+//     to add, remove, search, split and reorder them.
+// reflect: How do a Vec's length and its capacity differ?
+// reflect: Why does indexing past the end of a Vec panic while get returns
+//     an Option?
+
 // Vecs act sort of like arrays, but allow more flexibility
 #[test]
 fn making_an_empty_vec() {
     let vector: Vec<()> = __;
-    assert!(vector.len() == 0);
+    koan_assert!("A new Vec is empty.", vector.len() == 0);
 }
 
 // The vec! macro makes it easier to instantiate a vec
 #[test]
 fn vec_macro() {
     let vector = __;
-    assert!(vector.len() == 4);
+    koan_assert!("vec! creates a Vec with the elements you give it.", vector.len() == 4);
 }
 
 // Elements can be added to mutable Vecs
@@ -17,7 +25,7 @@ fn vec_macro() {
 fn adding_to_vecs() {
     let mut vector = vec![0, 1, 2];
     vector.__(3);
-    assert!(vector.len() == 4);
+    koan_assert!("push adds an element to the end of a Vec.", vector.len() == 4);
 }
 
 // Elements can also be removed from mutable Vecs
@@ -25,7 +33,7 @@ fn adding_to_vecs() {
 fn removing_from_end_of_vecs() {
     let mut vector = vec![0, 1, 2, 3];
     vector.__();
-    assert!(vector.len() == 3);
+    koan_assert!("pop removes the element at the end of a Vec.", vector.len() == 3);
 }
 
 // The elements removed can also be stored to another variable
@@ -33,7 +41,7 @@ fn removing_from_end_of_vecs() {
 fn storing_vec_elements() {
     let mut vector = vec![0, 1, 2, 3];
     let num = vector.__();
-    assert!(num == 3);
+    koan_assert!("A Vec holds its elements in order.", num == 3);
 }
 
 // Vecs can change size to fit their contents
@@ -42,9 +50,9 @@ fn storing_vec_elements() {
 fn changing_size_of_vecs() {
     let mut vector = vec![1, 2, 3];
     __;
-    assert!(vector.len() == 4);
+    koan_assert!("A Vec grows as elements are pushed.", vector.len() == 4);
     __;
-    assert!(vector.len() == 3);
+    koan_assert!("A Vec shrinks as elements are popped.", vector.len() == 3);
 }
 
 // Vecs have a certain maximum capacity at any given point
@@ -52,22 +60,22 @@ fn changing_size_of_vecs() {
 #[test]
 fn capacity() {
     let mut vector = vec![1, 2, 3, 4];
-    assert_eq!(vector.capacity(), 4);
+    koan_assert_eq!(vector.capacity(), 4, "vec! makes a Vec with just the capacity it needs.");
     vector.__;
-    assert_eq!(vector.capacity(), 8);
+    koan_assert_eq!(vector.capacity(), 8, "A Vec's capacity doubles when it runs out of room.");
     vector.pop();
-    assert_eq!(vector.capacity(), __);
+    koan_assert_eq!(__, vector.capacity(), "Removing elements does not give back capacity.");
 }
 
 // This extra memory can also be deallocated when its no longer needed
 #[test]
 fn shrink_vecs() {
     let mut vector = vec![1, 2, 3, 4, 5];
-    assert_eq!(vector.capacity(), 5);
+    koan_assert_eq!(vector.capacity(), 5, "vec! makes a Vec with just the capacity it needs.");
     vector.pop();
-    assert_eq!(vector.capacity(), __);
+    koan_assert_eq!(__, vector.capacity(), "Removing elements does not give back capacity.");
     vector.shrink_to_fit();
-    assert_eq!(vector.capacity(), __);
+    koan_assert_eq!(__, vector.capacity(), "shrink_to_fit gives back the room that is not used.");
 }
 
 // Vecs can reserve more space in order to prevent allocating several times
@@ -75,7 +83,7 @@ fn shrink_vecs() {
 fn reserve() {
     let mut vector = vec![1];
     vector.reserve(__);
-    assert_eq!(vector.capacity(), 8);
+    koan_assert_eq!(vector.capacity(), 8, "reserve makes room for at least as many more elements.");
 }
 
 // You can also cut Vecs down to size
@@ -83,7 +91,7 @@ fn reserve() {
 fn truncate() {
     let mut vector = vec![1, 2, 3, 4, 5];
     vector.__;
-    assert_eq!(vector, vec![1, 2]);
+    koan_assert_eq!(vector, vec![1, 2], "truncate keeps only the first elements of a Vec.");
 }
 
 // New elements can be stuffed into mutable Vectors
@@ -91,7 +99,7 @@ fn truncate() {
 fn insert() {
     let mut vector = vec![1, 2, 3, 4, 5];
     vector.insert(2, 6);
-    assert_eq!(vector, __);
+    koan_assert_eq!(__, vector, "insert places an element at an index, moving the rest along.");
 }
 
 // Elements can also be deleted a particular position in a Vector
@@ -99,7 +107,11 @@ fn insert() {
 fn remove() {
     let mut vector = vec![1, 2, 3, 4, 5];
     vector.remove(__);
-    assert_eq!(vector, vec![2, 3, 4, 5])
+    koan_assert_eq!(
+        vector,
+        vec![2, 3, 4, 5],
+        "remove takes out the element at an index, moving the rest back."
+    )
 }
 
 // We created an empty Vec in our first example,
@@ -107,8 +119,8 @@ fn remove() {
 #[test]
 fn empty_vecs() {
     let mut vector = vec![""; 0];
-    assert!(vector.is_empty()); // should return true
-    assert!(!vector.is_empty()); // should return false
+    koan_assert!("is_empty is true for a Vec with no elements.", vector.is_empty()); // should return true
+    koan_assert!("is_empty is false once a Vec has elements.", !vector.is_empty()); // should return false
 }
 
 // Elements of a Vec can be accessed by their index.
@@ -116,59 +128,69 @@ fn empty_vecs() {
 #[test]
 fn vec_indices() {
     let vector = vec!["red", "green", "refactor"];
-    assert_eq!(Some(&"green"), vector.get(__));
+    koan_assert_eq!(
+        vector.get(__),
+        Some(&"green"),
+        "get returns the element at an index, if there is one."
+    );
 }
 
 // You can also easily grab a Vec's first and last elements using the respective method
 #[test]
 fn first_and_last() {
     let vector = vec![false, true];
-    assert_eq!(vector.__, Some(&false));
-    assert_eq!(vector.__, Some(&true));
+    koan_assert_eq!(vector.__, Some(&false), "A Vec knows its first and its last element.");
+    koan_assert_eq!(vector.__, Some(&true), "A Vec knows its first and its last element.");
 }
 
 // It's also easy to check if a Vec contains a particular value
 #[test]
 fn contains_element() {
     let vector = vec!["Google", "Twitter", "Mozilla"];
-    assert!(vector.contains(__));
-    assert!(!vector.contains(__));
+    koan_assert!("contains is true when the Vec holds the element.", vector.contains(__));
+    koan_assert!("contains is false when the Vec does not hold the element.", !vector.contains(__));
 }
 
 // Similar to contains(), you can also check if a Vec begins with a particular element
 #[test]
 fn starts_with() {
     let vector = vec![0, 2, 4, 6];
-    assert!(vector.starts_with(&[__]));
+    koan_assert!(
+        "starts_with compares the beginning of a Vec with a slice.",
+        vector.starts_with(&[__])
+    );
 }
 
 // starts_with() can also accept multiple elements
 #[test]
 fn starts_with_2() {
     let vector = vec![0, 2, 4, 6];
-    assert!(vector.starts_with(__));
+    koan_assert!(
+        "starts_with compares the beginning of a Vec with a slice.",
+        vector.starts_with(__)
+    );
 }
 
 // The same can be said for ends_with
 #[test]
 fn ends_with() {
     let vector = __;
-    assert!(vector.ends_with(&[6]));
-    assert!(vector.ends_with(&[2, 4, 6]));
+    koan_assert!("ends_with compares the end of a Vec with a slice.", vector.ends_with(&[6]));
+    koan_assert!("ends_with compares the end of a Vec with a slice.", vector.ends_with(&[2, 4, 6]));
 }
 
 // Reversing a Vec is pretty easy in Rust
 #[test]
 fn reverse_vecs() {
     let mut vector = vec![1, 2, 3];
-    assert_eq!(vector.first(), Some(&3));
+    koan_assert_eq!(vector.first(), Some(&3), "reverse turns a Vec around in place.");
 }
 
 // You can also just swap two elements in a Vec
 #[test]
 fn trading_spaces() {
     let mut vector = vec![false, true];
-    assert_eq!(vector.first(), Some(&true));
+    koan_assert_eq!(vector.first(), Some(&true), "swap trades two elements by their indices.");
 }
 
 // Vecs can be broken up into equally sized chunks
@@ -176,7 +198,7 @@ fn trading_spaces() {
 fn chunking() {
     let vector = vec![1, 2, 1, 2];
     for chunk in vector.chunks(2) {
-        assert_eq!(chunk, &[__, __]);
+        koan_assert_eq!(&[__, __], chunk, "chunks splits a Vec into slices of a given length.");
     }
 }
 
@@ -185,8 +207,8 @@ fn chunking() {
 fn splitting() {
     let vector = vec!["Ruby", "Rust", "Python", "C++"];
     let (langs1, langs2) = vector.split_at(__);
-    assert_eq!(langs1, &["Ruby", "Rust"]);
-    assert_eq!(langs2, &[__, __]);
+    koan_assert_eq!(langs1, &["Ruby", "Rust"], "split_at divides a Vec in two at an index.");
+    koan_assert_eq!(&[__, __], langs2, "split_at divides a Vec in two at an index.");
 }
 
 // Or if you don't know the specific index, you can supply a condition at which to split
@@ -195,6 +217,6 @@ fn splitting() {
 fn more_splitting() {
     let vector = vec![1, 3, 4, 7, 9];
     for num in vector.split(|x| __) {
-        assert!(!num.contains(&4));
+        koan_assert!("split divides a Vec wherever an element matches.", !num.contains(&4));
     }
 }
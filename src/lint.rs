@@ -0,0 +1,80 @@
+use std::fs;
+
+use error::PathError;
+use koan;
+use paths;
+use sandbox::Sandbox;
+
+// A way in which the koans stray from how they are written.
+pub struct Finding {
+    // The file at fault, relative to the root of the repository.
+    pub file: String,
+    pub line: Option<usize>,
+    pub text: String,
+}
+
+impl Finding {
+    fn new(file: &str, line: Option<usize>, text: String) -> Finding {
+        Finding {
+            file: file.to_string(),
+            line,
+            text,
+        }
+    }
+}
+
+// Checks every file under src/koans/ against the conventions the koans are
+// written in: each test is introduced by a comment and leaves the learner a
+// blank to fill or an error to mend, and every file is listed in
+// src/koans.txt, as every listed koan has a file.
+pub fn lint(path: &[String]) -> Result<Vec<Finding>, PathError> {
+    let mut findings = Vec::new();
+    let modules = modules()?;
+
+    for (index, name) in path.iter().enumerate() {
+        if !modules.contains(name) {
+            findings.push(Finding::new("src/koans.txt", Some(index + 1), text!("missing-koan-file", koan = name)));
+        }
+    }
+
+    let sandbox = Sandbox::new("lint")?;
+
+    for module in &modules {
+        let file = format!("src/koans/{}.rs", module);
+        if !path.contains(module) {
+            findings.push(Finding::new(&file, None, text!("unlisted-koan")));
+        }
+
+        let (prelude, koans) = koan::split(&koan::read_source(module)?);
+        for koan in koans {
+            if koan.comment.trim().is_empty() {
+                findings.push(Finding::new(&file, Some(koan.line), text!("no-comment", koan = &koan.name)));
+            }
+
+            // A koan without a blank must fail for some other reason, such as
+            // an error in its code for the learner to mend
+            if koan.source.lines().all(|line| koan::blanks(line).is_empty()) {
+                sandbox.enter(&format!("{}{}", prelude, koan.source))?;
+                if sandbox.test_quietly()?.success() {
+                    findings.push(Finding::new(&file, Some(koan.line), text!("nothing-to-mend", koan = &koan.name)));
+                }
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+// The names of the koan files under src/koans/, in order.
+fn modules() -> Result<Vec<String>, PathError> {
+    let dir = paths::root().join("src").join("koans");
+    let entries = fs::read_dir(&dir).map_err(|error| PathError::io(&format!("read {}", dir.display()), error))?;
+
+    let mut modules: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter_map(|file| file.strip_suffix(".rs").map(|name| name.to_string()))
+        .collect();
+    modules.sort();
+    Ok(modules)
+}
@@ -0,0 +1,196 @@
+use std::env;
+use std::fs;
+use std::sync::OnceLock;
+
+use error::PathError;
+use koan::Koan;
+use paths;
+
+// What the runner says, in English, which every other language falls back
+// on. It is built in so that the runner can speak before it has found the
+// repository.
+const ENGLISH: &str = include_str!("locales/en/messages.ftl");
+
+static CATALOGUE: OnceLock<Catalogue> = OnceLock::new();
+
+// The messages of one language, read from a file in a small part of the
+// Fluent syntax (https://projectfluent.org/): a message is `key = text`, its
+// text may run on over indented lines below, and `{ $name }` is filled in
+// when the message is spoken. Lines beginning with # are comments.
+struct Catalogue {
+    lang: String,
+    messages: Vec<(String, String)>,
+    english: Vec<(String, String)>,
+}
+
+// Takes --lang <lang> or --lang=<lang> out of the command line, wherever it
+// is, returning the language asked for.
+pub fn take_lang(args: &mut Vec<String>) -> Option<String> {
+    let position = args.iter().position(|arg| arg == "--lang" || arg.starts_with("--lang="))?;
+    let arg = args.remove(position);
+    match arg.strip_prefix("--lang=") {
+        Some(lang) => Some(lang.to_string()),
+        None if position < args.len() => Some(args.remove(position)),
+        None => Some(String::new()),
+    }
+}
+
+// Chooses the language the runner speaks: the one asked for with --lang, or
+// else the one named by LC_ALL, LC_MESSAGES or LANG, if the koans have been
+// translated into it.
+pub fn choose(asked: Option<&str>) -> Result<(), PathError> {
+    let lang = match asked {
+        Some(lang) => {
+            let lang = language(lang);
+            if lang.is_empty() || (lang != "en" && !paths::locale(&lang).is_dir()) {
+                return Err(PathError::Usage(text(
+                    "no-such-language",
+                    &[("lang", &lang), ("languages", &languages().join(", "))],
+                )));
+            }
+            lang
+        }
+        None => ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .map(|value| language(&value))
+            .filter(|lang| paths::locale(lang).is_dir())
+            .unwrap_or_else(|| "en".to_string()),
+    };
+
+    let messages = if lang == "en" {
+        Vec::new()
+    } else {
+        let file = paths::locale(&lang).join("messages.ftl");
+        let source = fs::read_to_string(&file)
+            .map_err(|error| PathError::io(&format!("read {}", file.display()), error))?;
+        parse(&source)
+    };
+
+    CATALOGUE.get_or_init(|| Catalogue {
+        lang,
+        messages,
+        english: parse(ENGLISH),
+    });
+    Ok(())
+}
+
+// The language of a locale such as es_ES.UTF-8.
+fn language(locale: &str) -> String {
+    locale
+        .split(['_', '.', '@', '-'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+// The languages the koans have been translated into.
+fn languages() -> Vec<String> {
+    let mut languages: Vec<String> = fs::read_dir(paths::root().join("src").join("locales"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    languages.sort();
+    languages
+}
+
+fn catalogue() -> &'static Catalogue {
+    CATALOGUE.get_or_init(|| Catalogue {
+        lang: "en".to_string(),
+        messages: Vec::new(),
+        english: parse(ENGLISH),
+    })
+}
+
+// A message in the chosen language, with its variables filled in.
+pub fn text(key: &str, args: &[(&str, &str)]) -> String {
+    let catalogue = catalogue();
+    let message = lookup(&catalogue.messages, key)
+        .or_else(|| lookup(&catalogue.english, key))
+        .unwrap_or(key);
+
+    let mut text = message.to_string();
+    for &(name, value) in args {
+        text = text.replace(&format!("{{ ${} }}", name), value);
+    }
+    text
+}
+
+// The explanation of a koan in the chosen language, if it has been
+// translated, or else as the koan file has it.
+pub fn comment(module: &str, koan: &Koan) -> String {
+    overlay(module, &koan.name).unwrap_or_else(|| koan.comment.clone())
+}
+
+// A translation of part of a koan file, such as a koan's explanation or the
+// module's summary, kept in src/locales/<lang>/koans/<module>.ftl.
+pub fn overlay(module: &str, key: &str) -> Option<String> {
+    let catalogue = catalogue();
+    if catalogue.lang == "en" {
+        return None;
+    }
+    let file = paths::locale(&catalogue.lang)
+        .join("koans")
+        .join(format!("{}.ftl", module));
+    let source = fs::read_to_string(file).ok()?;
+    lookup(&parse(&source), key).map(|text| text.to_string())
+}
+
+fn lookup<'a>(messages: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    messages
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, text)| &text[..])
+}
+
+fn parse(source: &str) -> Vec<(String, String)> {
+    let mut messages: Vec<(String, String)> = Vec::new();
+    for line in source.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with(' ') {
+            if let Some((_, text)) = messages.last_mut() {
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(line.trim());
+            }
+        } else if let Some((key, text)) = line.split_once('=') {
+            messages.push((key.trim().to_string(), text.trim().to_string()));
+        }
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_messages_run_on_over_indented_lines() {
+        let source = "\
+# A comment
+ahead = The path lies ahead: { $koan }
+
+long =
+    First line
+    second line
+equals = a = b
+";
+        let messages = parse(source);
+        assert_eq!(
+            messages,
+            [
+                ("ahead".to_string(), "The path lies ahead: { $koan }".to_string()),
+                ("long".to_string(), "First line\nsecond line".to_string()),
+                ("equals".to_string(), "a = b".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+# What the runner says, in English. Every other language falls back on these
+# messages, so a translation need not be complete to be useful.
+
+## The path
+
+ahead = Ahead of you lies { $koan }.
+not-finished = Eternity lies ahead of us, and behind. Your path is not yet finished.
+no-more-tasks = There will be no more tasks.
+one-hand-clapping = What is the sound of one hand clapping (for you)?
+blocked = Meditate on your approach and return. Mountains are merely mountains.
+not-begun = You have not yet set foot upon the path.
+walked-module = You have walked the { $module } koans.
+reflect = Before you walk on, reflect:
+note-on = On { $code }:
+achievement-earned = Achievement earned: { $title }.
+
+## Commands
+
+no-such-command =
+    There is no path called { $command }.
+    Walk on with no command, answer or edit the current koan, ask for a hint,
+    the docs or an explanation of the error, advice on refactoring,
+    your achievements, a report (markdown, html, json) or the results of every koan (junit, tap).
+    Try any module or koan on its own, as in `try vec` or `try vec::chunking`.
+    Practise the modules you have completed with koans that vary each time.
+    Watch the path in the tui. Speak another language with --lang.
+    Maintainers may verify-all koans against a directory of solutions,
+    self-test that every untouched koan fails, lint-koans, or begin a new-koan.
+no-such-language = The koans have not been translated into { $lang }. They may be read in { $languages }.
+try-usage = Name the koans to try, as in `cargo run -- try vec` or `cargo run -- try vec::chunking`.
+no-such-module = There are no { $module } koans.
+no-such-koan = The { $module } koans hold no koan called { $koan }.
+tried-holds = { $koans } holds.
+tried-fails = { $koans } does not yet hold.
+nothing-to-practise = None of the modules you have completed can yet be practised. Complete the addition, integer or string koans, then return.
+practice-begun = A round of practice lies before you. Solve these, then run `cargo run -- practice` again:
+practice-done = The round is complete. Run `cargo run -- practice` again for another, different from the last.
+practice-not-done = The round is not yet complete. Return when these koans hold.
+tui-not-built = The tui was not built. Run it with: cargo run --features tui -- tui
+
+no-hints = The { $module } koans offer no hints. The answer lies within.
+hint = Hint: { $hint }
+
+no-docs = The { $module } koans point to no documentation. The answer lies within.
+read-docs = To understand the { $module } koans, read:
+docs-offline = To read these without a network, run `rustup component add rust-docs`.
+
+not-an-error-code = { $code } is not an error code. Error codes look like E0382.
+no-such-error-code = rustc knows of no error { $code }.
+nothing-to-explain = The { $module } koans pass. There is nothing to explain.
+no-code-to-explain = The compiler's errors in the { $module } koans carry no code to explain. Read them closely with `cargo run`.
+failing-not-erring = The { $module } koans compile, but do not yet hold. The lesson of the failing koan is its explanation.
+other-error-codes = The compiler also speaks of { $codes }. Ask after each with `cargo run -- explain <code>`.
+
+no-blanks = The { $module } koans have no blanks left to fill. The answer lies elsewhere.
+fill-the-blank = Fill the blank:
+blank-remains = The blank remains. Return when the answer comes to you.
+no-editor = Set $VISUAL or $EDITOR to your editor, or open { $file } yourself.
+editor-closed = { $editor } closed with { $status }.
+
+nothing-to-refactor = Solve a module of koans, then return to refactor it.
+no-advice = clippy has no advice on your koans. Your code speaks clearly.
+advice-for = The { $module } koans
+advice-not-blocking = Your path is not blocked by any of this. Refactor where it makes the koan's intent clearer, then walk on with `cargo run`.
+no-clippy = clippy is not installed. Install it with `rustup component add clippy`.
+clippy-failed = clippy could not look over your koans:
+
+streak = You have walked the path { $days } days in a row.
+
+no-such-report = A report may be written as markdown, html or json, not { $format }.
+no-such-results = Results may be written as junit or tap, not { $format }.
+results-amiss = { $count } koans on the path do not hold.
+module-did-not-compile = The module { $module } did not compile, so this koan could not run.
+
+## Maintaining the koans
+
+missing-solutions = { $dir } holds no solution for { $koans }.
+all-solvable = All { $count } koans on the path can be solved.
+not-all-solvable = Not every koan on the path can be solved.
+passes-untouched = { $koan } passes untouched.
+all-fail-untouched = Every koan on the path fails until it is solved.
+some-pass-untouched = { $count } koans pass before they are solved: { $koans }
+koans-well-written = Every koan is written as the path would have it.
+koans-astray = { $count } things in the koans stray from how they are written.
+missing-koan-file = { $koan } is on the path, but src/koans/{ $koan }.rs does not exist.
+unlisted-koan = This file is not on the path. List it in src/koans.txt, or remove it.
+no-comment = { $koan } is not introduced by a comment explaining what it teaches.
+nothing-to-mend = { $koan } has no blank to fill, and passes untouched. Leave the learner a blank or an error to mend.
+new-koan-usage = Name the new koans: cargo run -- new-koan <name> [--after <koan>]
+not-a-koan-name = { $name } cannot name koans. Use lowercase letters, digits and underscores, beginning with a letter.
+keyword-koan-name = { $name } is a Rust keyword, and cannot name koans.
+koan-exists = There are already { $name } koans.
+not-on-the-path = There are no { $name } koans on the path to place the new koans after.
+new-koan-written = The { $name } koans are written, and lie on the path after { $after }. Teach with { $koan }, solve them in { $solution }, and prove them with `cargo test --test solutions` and `cargo run -- self-test`.
+new-koan-written-first = The { $name } koans are written, and begin the path. Teach with { $koan }, solve them in { $solution }, and prove them with `cargo test --test solutions` and `cargo run -- self-test`.
+
+## The tui
+
+meditating = Meditating...
+tui-keys = q quit · r walk again · ↑↓ choose · PgUp/PgDn scroll
+not-on-path = This koan does not yet lie on your path.
+
+## Achievements
+
+first_step-title = The First Step
+first_step-description = Solve the first koan on the path.
+first_module-title = A Module Mastered
+first_module-description = Complete a module of many koans.
+unaided-title = The Answer Lies Within
+unaided-description = Complete a module that offers hints without asking for one.
+ownership-title = The Borrow Checker Is Your Friend
+ownership-description = Complete the ownership koans.
+streak_3-title = Daily Practice
+streak_3-description = Walk the path three days in a row.
+streak_7-title = A Week of Meditation
+streak_7-description = Walk the path seven days in a row.
+enlightenment-title = Enlightenment
+enlightenment-description = Complete every koan on the path.
+
+## When the path is blocked
+
+wrong-directory = No Cargo.toml could be found here or in any directory above. Run the koans from within the rust-koans repository.
+missing-manifest = src/koans.txt, which lists the koans, is missing. Restore it with `git checkout src/koans.txt`.
+cargo-not-found = cargo could not be found. Install Rust from https://www.rust-lang.org/ and make sure cargo is on your PATH.
+corrupt-progress = Line { $line } of { $file } cannot be understood: { $reason }. Mend or remove that line to continue on the path.
+io = Could not { $action }: { $error }.
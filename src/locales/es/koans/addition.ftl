@@ -0,0 +1,7 @@
+# Traducción de src/koans/addition.rs.
+
+summary = Los operadores aritméticos de Rust trabajan con números del mismo tipo, y el compilador comprueba que así sea.
+reflect =
+    ¿Qué pasaría si sumaras un i32 a un u8?
+    ¿Dónde más podrías ver el operador + en acción?
+addition = Uno más uno son dos. Completa la suma.
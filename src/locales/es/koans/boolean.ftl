@@ -0,0 +1,14 @@
+# Traducción de src/koans/boolean.rs.
+
+summary = Los booleanos son verdadero o falso, y == compara valores, no dónde se guardan.
+reflect =
+    ¿Por qué son iguales dos cadenas con el mismo contenido?
+    ¿Qué necesita un tipo para poder compararse con ==?
+truth =
+    Los booleanos pueden tener dos valores, true o false.
+    Dos valores iguales devuelven true al compararse con el operador ==
+falsehood =
+    Del mismo modo, dos valores distintos devuelven false al compararse con ==
+    El operador != devuelve true cuando los valores son distintos
+string_equality = Las cadenas también pueden compararse, y devuelven un booleano
+int_equality = Los enteros pueden compararse siempre que sean del mismo tipo
@@ -0,0 +1,7 @@
+# Traducción de src/koans/the_truth.rs. Cada koan se traduce por su nombre.
+
+summary = Cada koan es una prueba, y una prueba pasa cuando lo que afirma es verdad.
+reflect =
+    ¿Qué hace assert! cuando su condición es falsa?
+    ¿Por qué una prueba que no puede fallar no te enseña nada?
+the_truth = El camino comienza con lo que es verdad. Sustituye __ por un valor que lo sea.
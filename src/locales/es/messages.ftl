@@ -0,0 +1,103 @@
+# Lo que dice el corredor de koans, en español.
+
+## El camino
+
+ahead = Ante ti se encuentra { $koan }.
+not-finished = La eternidad se extiende ante nosotros, y detrás. Tu camino aún no ha terminado.
+no-more-tasks = No habrá más tareas.
+one-hand-clapping = ¿Cuál es el sonido de una sola mano aplaudiendo (para ti)?
+blocked = Medita sobre tu enfoque y vuelve. Las montañas son simplemente montañas.
+not-begun = Aún no has puesto un pie en el camino.
+walked-module = Has recorrido los koans de { $module }.
+reflect = Antes de seguir, reflexiona:
+note-on = Sobre { $code }:
+achievement-earned = Logro obtenido: { $title }.
+
+## Órdenes
+
+no-such-command =
+    No existe un camino llamado { $command }.
+    Sigue adelante sin ninguna orden, responde o edita el koan actual, pide una pista (hint),
+    la documentación (docs) o una explicación del error (explain), consejos para refactorizar (refactor),
+    tus logros (achievements), un informe (report: markdown, html, json) o los resultados de cada koan (results: junit, tap).
+    Prueba cualquier módulo o koan por sí solo, como en `try vec` o `try vec::chunking`.
+    Practica los módulos que has completado con koans que cambian cada vez (practice).
+    Observa el camino en la tui. Habla otro idioma con --lang.
+    Quienes mantienen los koans pueden comprobarlos con verify-all contra un directorio de soluciones,
+    con self-test que cada koan sin tocar falla o con lint-koans que están bien escritos, y comenzar otros con new-koan.
+no-such-language = Los koans no se han traducido a { $lang }. Pueden leerse en { $languages }.
+try-usage = Nombra los koans que quieres probar, como en `cargo run -- try vec` o `cargo run -- try vec::chunking`.
+no-such-module = No hay koans de { $module }.
+no-such-koan = Los koans de { $module } no tienen ningún koan llamado { $koan }.
+tried-holds = { $koans } se sostiene.
+tried-fails = { $koans } aún no se sostiene.
+nothing-to-practise = Ninguno de los módulos que has completado puede practicarse aún. Completa los koans de addition, integer o string, y vuelve.
+practice-begun = Te espera una ronda de práctica. Resuelve estos koans y ejecuta de nuevo `cargo run -- practice`:
+practice-done = La ronda está completa. Ejecuta de nuevo `cargo run -- practice` para otra, distinta de la anterior.
+practice-not-done = La ronda aún no está completa. Vuelve cuando estos koans se sostengan.
+tui-not-built = La tui no se compiló. Ejecútala con: cargo run --features tui -- tui
+
+no-hints = Los koans de { $module } no ofrecen pistas. La respuesta está en tu interior.
+hint = Pista: { $hint }
+
+no-docs = Los koans de { $module } no señalan ninguna documentación. La respuesta está en tu interior.
+read-docs = Para comprender los koans de { $module }, lee:
+docs-offline = Para leerlos sin conexión, ejecuta `rustup component add rust-docs`.
+
+not-an-error-code = { $code } no es un código de error. Los códigos de error son como E0382.
+no-such-error-code = rustc no conoce el error { $code }.
+nothing-to-explain = Los koans de { $module } pasan. No hay nada que explicar.
+no-code-to-explain = Los errores del compilador en los koans de { $module } no tienen código que explicar. Léelos con atención con `cargo run`.
+failing-not-erring = Los koans de { $module } compilan, pero aún no se cumplen. La lección del koan que falla es su explicación.
+other-error-codes = El compilador también habla de { $codes }. Pregunta por cada uno con `cargo run -- explain <código>`.
+
+no-blanks = A los koans de { $module } no les quedan huecos por rellenar. La respuesta está en otra parte.
+fill-the-blank = Rellena el hueco:
+blank-remains = El hueco sigue ahí. Vuelve cuando te llegue la respuesta.
+no-editor = Define $VISUAL o $EDITOR con tu editor, o abre { $file } tú mismo.
+editor-closed = { $editor } se cerró con { $status }.
+
+nothing-to-refactor = Resuelve un módulo de koans y vuelve para refactorizarlo.
+no-advice = clippy no tiene consejos sobre tus koans. Tu código habla con claridad.
+advice-for = Los koans de { $module }
+advice-not-blocking = Nada de esto bloquea tu camino. Refactoriza donde aclare la intención del koan y sigue adelante con `cargo run`.
+no-clippy = clippy no está instalado. Instálalo con `rustup component add clippy`.
+clippy-failed = clippy no pudo revisar tus koans:
+
+streak = Has recorrido el camino { $days } días seguidos.
+
+no-such-report = Un informe puede escribirse como markdown, html o json, no como { $format }.
+no-such-results = Los resultados pueden escribirse como junit o tap, no como { $format }.
+results-amiss = { $count } koans del camino no se sostienen.
+module-did-not-compile = El módulo { $module } no compiló, así que este koan no pudo ejecutarse.
+
+## La tui
+
+meditating = Meditando...
+tui-keys = q salir · r recorrer de nuevo · ↑↓ elegir · RePág/AvPág desplazar
+not-on-path = Este koan aún no está en tu camino.
+
+## Logros
+
+first_step-title = El primer paso
+first_step-description = Resuelve el primer koan del camino.
+first_module-title = Un módulo dominado
+first_module-description = Completa un módulo de muchos koans.
+unaided-title = La respuesta está en tu interior
+unaided-description = Completa un módulo que ofrece pistas sin pedir ninguna.
+ownership-title = El verificador de préstamos es tu amigo
+ownership-description = Completa los koans de ownership.
+streak_3-title = Práctica diaria
+streak_3-description = Recorre el camino tres días seguidos.
+streak_7-title = Una semana de meditación
+streak_7-description = Recorre el camino siete días seguidos.
+enlightenment-title = Iluminación
+enlightenment-description = Completa todos los koans del camino.
+
+## Cuando el camino está bloqueado
+
+wrong-directory = No se encontró ningún Cargo.toml aquí ni en ningún directorio superior. Ejecuta los koans desde el repositorio rust-koans.
+missing-manifest = Falta src/koans.txt, que enumera los koans. Restáuralo con `git checkout src/koans.txt`.
+cargo-not-found = No se encontró cargo. Instala Rust desde https://www.rust-lang.org/ y asegúrate de que cargo esté en tu PATH.
+corrupt-progress = No se entiende la línea { $line } de { $file }: { $reason }. Corrige o elimina esa línea para seguir en el camino.
+io = No se pudo { $action }: { $error }.
@@ -1,63 +1,887 @@
 #[cfg(not(test))]
-use std::process::Command;
+use std::process::{self, Command, Output, Stdio};
 
 #[cfg(not(test))]
-use std::fs::{File, OpenOptions};
+use std::env;
 
 #[cfg(not(test))]
-use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+#[cfg(not(test))]
+use std::fs::File;
+
+#[cfg(not(test))]
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+#[cfg(not(test))]
+use std::thread;
+
+#[cfg(all(not(test), feature = "tui"))]
+extern crate ratatui;
+
+// Speaks a message from the catalogue in the learner's language, such as
+// `text!("ahead", koan = name)`.
+#[cfg(not(test))]
+macro_rules! text {
+    ($key:expr) => {
+        ::locale::text($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+) => {
+        ::locale::text($key, &[$((stringify!($name), &$value[..])),+])
+    };
+}
+
+#[cfg(not(test))]
+mod achievements;
+
+#[cfg(not(test))]
+mod answer;
+
+#[cfg(not(test))]
+mod docs;
+
+#[cfg(not(test))]
+mod editor;
+
+#[cfg(not(test))]
+mod error;
+
+#[cfg(not(test))]
+mod explain;
+
+#[cfg(not(test))]
+mod koan;
+
+#[cfg(not(test))]
+mod lint;
+
+#[cfg(not(test))]
+mod locale;
+
+#[cfg(not(test))]
+mod module;
+
+#[cfg(not(test))]
+mod output;
+
+#[cfg(not(test))]
+mod paths;
+
+#[cfg(not(test))]
+mod practice;
+
+#[cfg(not(test))]
+mod progress;
+
+#[cfg(not(test))]
+mod refactor;
+
+#[cfg(not(test))]
+mod report;
+
+#[cfg(not(test))]
+mod results;
+
+#[cfg(not(test))]
+mod sandbox;
+
+#[cfg(not(test))]
+mod scaffold;
+
+#[cfg(all(not(test), feature = "tui"))]
+mod tui;
+
+#[cfg(not(test))]
+use error::PathError;
+
+#[cfg(not(test))]
+use output::{paint, paint_err, Style};
+
+#[cfg(not(test))]
+use progress::Progress;
 
 #[cfg(not(test))]
 fn main() {
-    let message = if walk_the_path() {
-        if seek_the_path() {
-            "Eternity lies ahead of us, and behind. Your path is not yet finished."
-        } else {
-            "What is the sound of one hand clapping (for you)?"
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let lang = locale::take_lang(&mut args);
+
+    let walked = paths::find_root()
+        .and_then(|_| locale::choose(lang.as_deref()))
+        .and_then(|_| follow(&args));
+    if let Err(error) = walked {
+        eprintln!("{}", paint_err(Style::Fail, &error.to_string()));
+        process::exit(error.exit_code());
+    }
+}
+
+#[cfg(not(test))]
+fn follow(args: &[String]) -> Result<(), PathError> {
+    match args.first().map(|arg| &arg[..]) {
+        None => meditate(),
+        Some("hint") => seek_a_hint(),
+        Some("docs") => read_the_docs(),
+        Some("explain") => explain_the_error(args.get(1).map(|arg| &arg[..])),
+        Some("answer") => answer_a_koan(),
+        Some("edit") => edit_a_koan(),
+        Some("refactor") => seek_advice(),
+        Some("achievements") => list_achievements(),
+        Some("report") => write_report(args.get(1).map(|arg| &arg[..]).unwrap_or("markdown")),
+        Some("results") => write_results(args.get(1).map(|arg| &arg[..]).unwrap_or("tap")),
+        Some("verify-all") => verify_all(args.get(1).map(|arg| &arg[..])),
+        Some("self-test") => self_test(),
+        Some("new-koan") => new_koan(&args[1..]),
+        Some("lint-koans") => lint_koans(),
+        Some("try") => try_a_koan(args.get(1).map(|arg| &arg[..])),
+        Some("practice") => practise(),
+        Some("tui") => watch(),
+        Some(command) => Err(PathError::Usage(text!("no-such-command", command = command))),
+    }
+}
+
+#[cfg(not(test))]
+fn meditate() -> Result<(), PathError> {
+    let mut progress = Progress::load()?;
+    let current = current_koan(&progress)?;
+    let (passed, said) = walk_the_path()?;
+
+    let (step, completed) = take_a_step(&mut progress, passed)?;
+    if let Some(ref current) = current {
+        match step {
+            Step::Blocked => {
+                explain_in_your_language(&said, current)?;
+                share_notes(&said, current)?;
+            }
+            _ if completed => reflect_on(current)?,
+            _ => {}
         }
-    } else {
-        "Meditate on your approach and return. Mountains are merely mountains."
+    }
+
+    for milestone in achievements::award(&mut progress, &the_path()?)? {
+        println!(
+            "{} {}",
+            paint(Style::Highlight, &text!("achievement-earned", title = milestone.title())),
+            milestone.description()
+        );
+    }
+
+    let message = match step {
+        Step::Onward(next_koan) => {
+            println!("{}", text!("ahead", koan = paint(Style::Highlight, &next_koan)));
+            paint(Style::Pass, &text!("not-finished"))
+        }
+        Step::Finished => {
+            println!("{}", text!("no-more-tasks"));
+            paint(Style::Pass, &text!("one-hand-clapping"))
+        }
+        Step::Blocked => paint(Style::Fail, &text!("blocked")),
     };
 
+    progress.save()?;
     println!("{}", message);
+    Ok(())
+}
+
+// Looks back on a module of koans the learner has just completed, with what
+// it taught and questions to consider before walking on.
+#[cfg(not(test))]
+fn reflect_on(module: &str) -> Result<(), PathError> {
+    let source = koan::read_source(module)?;
+    let summary = match locale::overlay(module, "summary") {
+        Some(summary) => vec![summary],
+        None => koan::tagged(&source, "summary"),
+    };
+    let questions = match locale::overlay(module, "reflect") {
+        Some(questions) => questions.lines().map(|question| question.to_string()).collect(),
+        None => koan::tagged(&source, "reflect"),
+    };
+    if summary.is_empty() && questions.is_empty() {
+        return Ok(());
+    }
+
+    println!("{}", text!("walked-module", module = paint(Style::Highlight, module)));
+    for line in &summary {
+        println!("    {}", paint(Style::Comment, line));
+    }
+    if !questions.is_empty() {
+        println!("{}", text!("reflect"));
+        for question in &questions {
+            println!("    - {}", question);
+        }
+    }
+    println!();
+    Ok(())
+}
+
+// Repeats the explanation of the koan at hand in the learner's language, if
+// it has been translated, since the koan file itself is in English.
+#[cfg(not(test))]
+fn explain_in_your_language(said: &str, current: &str) -> Result<(), PathError> {
+    let koans = koan::koans_in(&koan::read_source(current)?);
+    if let Some(koan) = koan::at_hand(koans, said, current) {
+        if let Some(comment) = locale::overlay(current, &koan.name) {
+            println!("{}", paint(Style::Highlight, &koan.name));
+            for line in comment.lines() {
+                println!("    {}", paint(Style::Comment, line));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Shares the koans' own word on each error the compiler raised against the
+// current koan.
+#[cfg(not(test))]
+fn share_notes(said: &str, current: &str) -> Result<(), PathError> {
+    let mut shared: Vec<String> = Vec::new();
+    for code in explain::diagnostics(said, current).into_iter().filter_map(|diagnostic| diagnostic.code) {
+        if shared.contains(&code) {
+            continue;
+        }
+        if let Some(note) = explain::note(&code)? {
+            println!("{}", paint(Style::Highlight, &text!("note-on", code = code)));
+            for line in note.lines() {
+                println!("    {}", paint(Style::Comment, line));
+            }
+        }
+        shared.push(code);
+    }
+    Ok(())
+}
+
+#[cfg(not(test))]
+enum Step {
+    // The current koan is not yet solved.
+    Blocked,
+    // The current koan is solved, and the named koan lies ahead.
+    Onward(String),
+    // Every koan has been solved.
+    Finished,
+}
+
+// Records an attempt at the current koan and, if it passed, moves the
+// learner on along the path. Also says whether the current koan was completed
+// by this step, rather than on an earlier one.
+#[cfg(not(test))]
+fn take_a_step(progress: &mut Progress, passed: bool) -> Result<(Step, bool), PathError> {
+    progress.walk_today();
+    let mut completed = false;
+    if let Some(current) = current_koan(progress)? {
+        let entry = progress.entry_mut(&current);
+        entry.attempts += 1;
+        if passed && entry.completed.is_none() {
+            entry.completed = Some(progress::now());
+            completed = true;
+        }
+    }
+
+    let step = if !passed {
+        Step::Blocked
+    } else {
+        seek_the_path(progress)?.map_or(Step::Finished, Step::Onward)
+    };
+    Ok((step, completed))
+}
+
+#[cfg(all(not(test), feature = "tui"))]
+fn watch() -> Result<(), PathError> {
+    tui::run()
+}
+
+#[cfg(all(not(test), not(feature = "tui")))]
+fn watch() -> Result<(), PathError> {
+    Err(PathError::Usage(text!("tui-not-built")))
+}
+
+// Fills in the current koan's blanks from the terminal, then walks the path
+// to see if the answer holds.
+#[cfg(not(test))]
+fn answer_a_koan() -> Result<(), PathError> {
+    let (_, current) = match koan_at_hand()? {
+        Some(at_hand) => at_hand,
+        None => return Ok(()),
+    };
+
+    if answer::fill_a_blank(&current)? {
+        meditate()
+    } else {
+        Ok(())
+    }
+}
+
+// Opens the current koan in the learner's editor, at its first blank or,
+// failing that, where cargo says it went wrong.
+#[cfg(not(test))]
+fn edit_a_koan() -> Result<(), PathError> {
+    let (_, current) = match koan_at_hand()? {
+        Some(at_hand) => at_hand,
+        None => return Ok(()),
+    };
+
+    let line = match koan::first_blank(&koan::read_source(&current)?) {
+        Some(line) => line,
+        None => {
+            let (_, said) = walk_the_path_quietly()?;
+            koan::error_line(&said, &current).unwrap_or(1)
+        }
+    };
+
+    editor::open(&paths::koan(&current), line)
 }
 
+#[cfg(not(test))]
+fn seek_a_hint() -> Result<(), PathError> {
+    let (mut progress, current) = match koan_at_hand()? {
+        Some(at_hand) => at_hand,
+        None => return Ok(()),
+    };
+
+    let hints = koan::tagged(&koan::read_source(&current)?, "hint");
+
+    if hints.is_empty() {
+        println!("{}", text!("no-hints", module = paint(Style::Highlight, &current)));
+        return Ok(());
+    }
+
+    let entry = progress.entry_mut(&current);
+    if (entry.hints as usize) < hints.len() {
+        entry.hints += 1;
+    }
+    for hint in hints.iter().take(entry.hints as usize) {
+        println!("{}", paint(Style::Comment, &text!("hint", hint = hint)));
+    }
+    progress.save()
+}
+
+// Walks a single module of koans, or a single koan within one, in the
+// sandbox, wherever it lies on the path and whether or not it has been
+// reached, so that it may be shown or revisited on its own.
+#[cfg(not(test))]
+fn try_a_koan(target: Option<&str>) -> Result<(), PathError> {
+    let target = target.ok_or_else(|| PathError::Usage(text!("try-usage")))?;
+    let (module, name) = match target.split_once("::") {
+        Some((module, name)) => (module, Some(name)),
+        None => (target, None),
+    };
+    if !the_path()?.iter().any(|koan| koan == module) {
+        return Err(PathError::Usage(text!("no-such-module", module = module)));
+    }
+
+    let sandbox = sandbox::Sandbox::new("try")?;
+    let held = match name {
+        Some(name) => {
+            let source = koan::read_source(module)?;
+            let koans = koan::koans_in(&source);
+            if !koans.iter().any(|koan| koan.name == name) {
+                return Err(PathError::Usage(text!("no-such-koan", module = module, koan = name)));
+            }
+            let alone = sandbox.keep(&format!("{}.rs", module), &koan::alone(&source, &koans, name))?;
+            sandbox.walk(std::slice::from_ref(&alone))?;
+
+            // What is said of the copy is said of the koan file itself, which
+            // it matches line for line
+            let output = sandbox.test_focused_quietly()?;
+            let (copy, file) = (alone.display().to_string(), paths::koan(module).display().to_string());
+            eprint!("{}", String::from_utf8_lossy(&output.stderr).replace(&copy, &file));
+            print!("{}", String::from_utf8_lossy(&output.stdout).replace(&copy, &file));
+            output.status.success()
+        }
+        None => {
+            sandbox.walk(&[paths::koan(module)])?;
+            sandbox.test_focused()?.success()
+        }
+    };
+
+    if !held {
+        return Err(PathError::KoansAmiss(text!("tried-fails", koans = target)));
+    }
+    println!("{}", paint(Style::Pass, &text!("tried-holds", koans = target)));
+    Ok(())
+}
+
+// Practises the modules the learner has completed with koans that vary from
+// round to round. The first run writes a round out under practice/; once
+// every koan in it holds, the round is cleared and a new seed chosen, so
+// that the next is filled in afresh.
+#[cfg(not(test))]
+fn practise() -> Result<(), PathError> {
+    let begun = practice::begun();
+    if begun.is_empty() {
+        if practice::modules().is_empty() {
+            println!("{}", text!("nothing-to-practise"));
+            return Ok(());
+        }
+        // Record the seed the first round was filled in with, so that it
+        // stays the same until the round is finished
+        let mut progress = Progress::load()?;
+        if progress.seed == 0 {
+            progress.seed = practice::seed();
+            progress.save()?;
+        }
+
+        println!("{}", text!("practice-begun"));
+        for file in practice::begin()? {
+            let file = file.strip_prefix(paths::root()).unwrap_or(&file).display().to_string();
+            println!("    {}", paint(Style::Highlight, &file));
+        }
+        return Ok(());
+    }
+
+    let sandbox = sandbox::Sandbox::new("practice")?;
+    sandbox.walk(&begun)?;
+    if !sandbox.test_focused()?.success() {
+        return Err(PathError::KoansAmiss(text!("practice-not-done")));
+    }
+    let mut progress = Progress::load()?;
+    progress.seed = progress::now().max(progress.seed + 1);
+    progress.save()?;
+    practice::finish(&begun)?;
+    println!("{}", paint(Style::Pass, &text!("practice-done")));
+    Ok(())
+}
+
+// Points the learner at the documentation for the current koan, read from
+// the copy that `rustup doc` opens where there is one.
+#[cfg(not(test))]
+fn read_the_docs() -> Result<(), PathError> {
+    let (_, current) = match koan_at_hand()? {
+        Some(at_hand) => at_hand,
+        None => return Ok(()),
+    };
+
+    let references = docs::references(&koan::read_source(&current)?);
+    if references.is_empty() {
+        println!("{}", text!("no-docs", module = paint(Style::Highlight, &current)));
+        return Ok(());
+    }
+
+    let local_root = docs::local_root();
+    println!("{}", text!("read-docs", module = paint(Style::Highlight, &current)));
+    for reference in &references {
+        println!("    {}", docs::resolve(local_root.as_ref(), reference));
+    }
+    if local_root.is_none() {
+        println!("{}", paint(Style::Comment, &text!("docs-offline")));
+    }
+    Ok(())
+}
+
+// Explains the error the compiler raised against the current koan, or the
+// error code given.
+#[cfg(not(test))]
+fn explain_the_error(code: Option<&str>) -> Result<(), PathError> {
+    if let Some(code) = code {
+        let code = code.to_uppercase();
+        if !explain::is_error_code(&code) {
+            return Err(PathError::Usage(text!("not-an-error-code", code = code)));
+        }
+        if let Some(note) = explain::note(&code)? {
+            println!("{}", note);
+        }
+        print!("{}", explain::rustc_explain(&code)?);
+        return Ok(());
+    }
+
+    let (_, current) = match koan_at_hand()? {
+        Some(at_hand) => at_hand,
+        None => return Ok(()),
+    };
+
+    let (passed, said) = walk_the_path_quietly()?;
+    let diagnostics = explain::diagnostics(&said, &current);
+    let (diagnostic, code) = match diagnostics.iter().find_map(|found| found.code.as_ref().map(|code| (found, code))) {
+        Some(found) => found,
+        None if passed => {
+            println!("{}", text!("nothing-to-explain", module = paint(Style::Highlight, &current)));
+            return Ok(());
+        }
+        None if !diagnostics.is_empty() => {
+            println!("{}", text!("no-code-to-explain", module = paint(Style::Highlight, &current)));
+            return Ok(());
+        }
+        None => {
+            println!("{}", text!("failing-not-erring", module = paint(Style::Highlight, &current)));
+            return Ok(());
+        }
+    };
+
+    print!("{}", diagnostic.text);
+    println!();
+    if let Some(note) = explain::note(code)? {
+        println!("{}", note);
+    }
+    print!("{}", explain::rustc_explain(code)?);
+
+    let mut others: Vec<&str> = Vec::new();
+    for other in diagnostics.iter().filter_map(|other| other.code.as_ref()) {
+        if other != code && !others.contains(&&other[..]) {
+            others.push(other);
+        }
+    }
+    if !others.is_empty() {
+        println!();
+        println!("{}", paint(Style::Comment, &text!("other-error-codes", codes = others.join(", "))));
+    }
+    Ok(())
+}
+
+// Looks over the koans the learner has solved for ways they might be written
+// better. The advice is for reflection only; it never blocks the path.
+#[cfg(not(test))]
+fn seek_advice() -> Result<(), PathError> {
+    let progress = Progress::load()?;
+    let solved: Vec<String> = progress.completed().iter().map(|entry| entry.name.clone()).collect();
+    if solved.is_empty() {
+        println!("{}", text!("nothing-to-refactor"));
+        return Ok(());
+    }
+
+    let advice = refactor::seek_advice(&solved)?;
+    if advice.is_empty() {
+        println!("{}", paint(Style::Pass, &text!("no-advice")));
+        return Ok(());
+    }
+
+    for module in &solved {
+        let mut advised = advice.iter().filter(|advice| advice.module == *module).peekable();
+        if advised.peek().is_none() {
+            continue;
+        }
+        println!("{}", paint(Style::Highlight, &text!("advice-for", module = module)));
+        for advice in advised {
+            let koan = advice.koan.as_ref().map_or("", |koan| &koan[..]);
+            println!("  {} ({}.rs:{})", koan, module, advice.line);
+            for line in advice.text.lines() {
+                println!("    {}", paint(Style::Comment, line));
+            }
+        }
+        println!();
+    }
+    println!("{}", text!("advice-not-blocking"));
+    Ok(())
+}
+
+#[cfg(not(test))]
+fn list_achievements() -> Result<(), PathError> {
+    let progress = Progress::load()?;
+    for milestone in achievements::MILESTONES.iter() {
+        if progress.has_earned(milestone.id) {
+            println!("{} {}", paint(Style::Pass, &format!("✓ {}.", milestone.title())), milestone.description());
+        } else {
+            println!("{}", paint(Style::Comment, &format!("· {}. {}", milestone.title(), milestone.description())));
+        }
+    }
+
+    if progress.streak.days > 1 {
+        println!("{}", text!("streak", days = progress.streak.days.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(not(test))]
+fn write_report(format: &str) -> Result<(), PathError> {
+    let format = report::Format::parse(format).ok_or_else(|| {
+        PathError::Usage(text!("no-such-report", format = format))
+    })?;
+
+    let total = the_path()?.len();
+    let progress = Progress::load()?;
+    print!("{}", report::render(&format, &progress, total)?);
+    Ok(())
+}
+
+#[cfg(not(test))]
+fn write_results(format: &str) -> Result<(), PathError> {
+    let format = results::Format::parse(format).ok_or_else(|| {
+        PathError::Usage(text!("no-such-results", format = format))
+    })?;
+
+    let progress = Progress::load()?;
+    let cases = results::gather(&the_path()?, &unlocked_koans(&progress)?)?;
+    print!("{}", results::render(&format, &cases));
+    let amiss = cases.iter().filter(|case| case.is_failure()).count();
+    if amiss > 0 {
+        return Err(PathError::KoansAmiss(text!("results-amiss", count = amiss.to_string())));
+    }
+    Ok(())
+}
+
+// Walks the whole path at once against a directory of solved koans, proving
+// that every koan can be solved. A directory given is found from where the
+// runner was started; the reference solutions are found from the root.
+#[cfg(not(test))]
+fn verify_all(solutions: Option<&str>) -> Result<(), PathError> {
+    let mut koans = Vec::new();
+    let mut missing = Vec::new();
+    for name in the_path()? {
+        let solution = match solutions {
+            Some(dir) => Path::new(dir).join(format!("{}.rs", name)),
+            None => paths::solution(&name),
+        };
+        if solution.is_file() {
+            koans.push(solution);
+        } else {
+            missing.push(name);
+        }
+    }
+
+    if !missing.is_empty() {
+        let dir = solutions.unwrap_or("solutions");
+        return Err(PathError::KoansAmiss(text!("missing-solutions", dir = dir, koans = missing.join(", "))));
+    }
+
+    let sandbox = sandbox::Sandbox::new("verify-all")?;
+    sandbox.walk(&koans)?;
+    if !sandbox.test()?.success() {
+        return Err(PathError::KoansAmiss(text!("not-all-solvable")));
+    }
+    println!("{}", paint(Style::Pass, &text!("all-solvable", count = koans.len().to_string())));
+    Ok(())
+}
+
+// Compiles each untouched koan on its own, alongside the rest of its file,
+// and makes sure that it fails. A koan that passes before the learner has
+// touched it teaches nothing.
+#[cfg(not(test))]
+fn self_test() -> Result<(), PathError> {
+    let sandbox = sandbox::Sandbox::new("self-test")?;
+    let mut green = Vec::new();
+
+    for module in the_path()? {
+        let (prelude, koans) = koan::split(&koan::read_source(&module)?);
+        for koan in koans {
+            sandbox.enter(&format!("{}{}", prelude, koan.source))?;
+            if sandbox.test_quietly()?.success() {
+                let name = format!("{}::{}", module, koan.name);
+                println!("{}", paint(Style::Fail, &text!("passes-untouched", koan = name)));
+                green.push(name);
+            }
+        }
+    }
+
+    if !green.is_empty() {
+        let count = green.len().to_string();
+        return Err(PathError::KoansAmiss(text!("some-pass-untouched", count = count, koans = green.join(", "))));
+    }
+    println!("{}", paint(Style::Pass, &text!("all-fail-untouched")));
+    Ok(())
+}
+
+// Checks that the koans are written as koans should be, reporting each way
+// in which they stray.
+#[cfg(not(test))]
+fn lint_koans() -> Result<(), PathError> {
+    let findings = lint::lint(&the_path()?)?;
+    if findings.is_empty() {
+        println!("{}", paint(Style::Pass, &text!("koans-well-written")));
+        return Ok(());
+    }
+
+    for finding in &findings {
+        let site = match finding.line {
+            Some(line) => format!("{}:{}", finding.file, line),
+            None => finding.file.clone(),
+        };
+        println!("{}: {}", paint(Style::Highlight, &site), finding.text);
+    }
+    Err(PathError::KoansAmiss(text!("koans-astray", count = findings.len().to_string())))
+}
+
+// Begins a new module of koans from a template, with a solution to match,
+// and places it on the path: at the end, or after the koans named with
+// --after.
+#[cfg(not(test))]
+fn new_koan(args: &[String]) -> Result<(), PathError> {
+    let (name, after) = match args {
+        [name] => (name, None),
+        [name, flag, after] if flag == "--after" => (name, Some(after)),
+        _ => return Err(PathError::Usage(text!("new-koan-usage"))),
+    };
+
+    let path = the_path()?;
+    if let Some(unfit) = scaffold::unfit(name, &path) {
+        return Err(PathError::Usage(unfit));
+    }
+    let position = match after {
+        Some(after) => match path.iter().position(|koan| koan == after) {
+            Some(index) => index + 1,
+            None => return Err(PathError::Usage(text!("not-on-the-path", name = after))),
+        },
+        None => path.len(),
+    };
+
+    scaffold::new_koan(name, &path, position)?;
+    let koan = format!("src/koans/{}.rs", name);
+    let solution = format!("solutions/{}.rs", name);
+    let written = match position.checked_sub(1).and_then(|index| path.get(index)) {
+        Some(after) => text!("new-koan-written", name = name, after = after, koan = koan, solution = solution),
+        None => text!("new-koan-written-first", name = name, koan = koan, solution = solution),
+    };
+    println!("{}", paint(Style::Pass, &written));
+    Ok(())
+}
+
+#[cfg(test)]
+#[macro_use]
+mod harness;
+
+// Walks a koan file in a module of its own, such as `koan!(vec, "vec")`.
 #[allow(unused_macros)]
 macro_rules! koan {
-    ($name:expr) => (
-        include!(concat!("koans/", $name, ".rs"));
+    ($module:ident, $name:expr) => (
+        mod $module {
+            include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/koans/", $name, ".rs"));
+        }
     );
 }
 
+// Every koan in the manifest, in the order they are met.
 #[cfg(not(test))]
-fn seek_the_path() -> bool {
-    let mut koans = BufReader::new(File::open("src/koans.txt").unwrap()).lines();
-    let mut path = OpenOptions::new()
-        .read(true)
-        .append(true)
-        .open("src/path_to_enlightenment.rs")
-        .unwrap();
-    let passed_count = BufReader::new(&path).lines().count();
+fn the_path() -> Result<Vec<String>, PathError> {
+    let manifest = File::open(paths::manifest()).map_err(|_| PathError::MissingManifest)?;
+    BufReader::new(manifest)
+        .lines()
+        .collect::<Result<_, _>>()
+        .map_err(|error| PathError::io("read src/koans.txt", error))
+}
 
-    if let Some(Ok(next_koan)) = koans.nth(passed_count) {
-        println!("Ahead of you lies {}.", next_koan);
-        write!(&mut path, "koan!(\"{}\");\n", next_koan).unwrap();
-        true
-    } else {
-        println!("There will be no more tasks.");
-        false
+// The koans added to the path so far, in the order they are met. The first
+// koan is always on the path.
+#[cfg(not(test))]
+fn unlocked_koans(progress: &Progress) -> Result<Vec<String>, PathError> {
+    let known = the_path()?;
+
+    for (index, entry) in progress.entries.iter().enumerate() {
+        if !known.contains(&entry.name) {
+            return Err(PathError::CorruptProgress {
+                file: ".progress".to_string(),
+                line: index + 1,
+                reason: format!("`{}` does not name a koan in src/koans.txt", entry.name),
+            });
+        }
+    }
+
+    Ok(known
+        .into_iter()
+        .enumerate()
+        .filter(|&(index, ref name)| index == 0 || progress.entries.iter().any(|entry| entry.name == *name))
+        .map(|(_, name)| name)
+        .collect())
+}
+
+// The koan most recently added to the path, which the learner is working on.
+#[cfg(not(test))]
+fn current_koan(progress: &Progress) -> Result<Option<String>, PathError> {
+    unlocked_koans(progress).map(|mut unlocked| unlocked.pop())
+}
+
+// The learner's progress and the koan at hand, or None, once the learner has
+// been told so, if they have not yet begun the path.
+#[cfg(not(test))]
+fn koan_at_hand() -> Result<Option<(Progress, String)>, PathError> {
+    let progress = Progress::load()?;
+    match current_koan(&progress)? {
+        Some(current) => Ok(Some((progress, current))),
+        None => {
+            println!("{}", text!("not-begun"));
+            Ok(None)
+        }
     }
 }
 
+// Adds the next koan to the path, if any remain.
 #[cfg(not(test))]
-fn walk_the_path() -> bool {
-    Command::new("cargo")
+fn seek_the_path(progress: &mut Progress) -> Result<Option<String>, PathError> {
+    let passed_count = unlocked_koans(progress)?.len();
+    let next_koan = the_path()?.into_iter().nth(passed_count);
+
+    if let Some(ref next_koan) = next_koan {
+        progress.entry_mut(next_koan);
+    }
+    Ok(next_koan)
+}
+
+// Walks the path, passing on what cargo says to the learner as it says it,
+// and keeps it for the runner to read.
+#[cfg(not(test))]
+fn walk_the_path() -> Result<(bool, String), PathError> {
+    let mut child = cargo_test(output::captured_cargo_colour())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(PathError::cargo)?;
+
+    // Cargo speaks on both at once, so each is passed on from a thread of
+    // its own
+    let stderr = child.stderr.take().map(|from| thread::spawn(move || tee(from, io::stderr())));
+    let stdout = match child.stdout.take() {
+        Some(from) => tee(from, io::stdout()),
+        None => Ok(Vec::new()),
+    };
+    let stderr = match stderr {
+        Some(thread) => thread.join().unwrap_or_else(|_| Ok(Vec::new())),
+        None => Ok(Vec::new()),
+    };
+    let passing_on = |error| PathError::io("pass on what cargo said", error);
+    let (stdout, stderr) = (stdout.map_err(passing_on)?, stderr.map_err(passing_on)?);
+
+    let status = child.wait().map_err(PathError::cargo)?;
+    let mut said = String::from_utf8_lossy(&stderr).into_owned();
+    said.push_str(&String::from_utf8_lossy(&stdout));
+    Ok((status.success(), output::unpaint(&said)))
+}
+
+// Passes on what is read as soon as it is read, keeping a copy.
+#[cfg(not(test))]
+fn tee<R: Read, W: Write>(mut from: R, mut to: W) -> io::Result<Vec<u8>> {
+    let mut kept = Vec::new();
+    let mut buffer = [0; 4096];
+    loop {
+        let read = from.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(kept);
+        }
+        to.write_all(&buffer[..read])?;
+        to.flush()?;
+        kept.extend_from_slice(&buffer[..read]);
+    }
+}
+
+// Walks the path as walk_the_path does, but keeps what cargo says rather
+// than letting it speak to the terminal.
+#[cfg(not(test))]
+fn walk_the_path_quietly() -> Result<(bool, String), PathError> {
+    let output = cargo_test("never")
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .map_err(PathError::cargo)?;
+    Ok((output.status.success(), said(&output)))
+}
+
+// Tests the learner's path alone, leaving the maintainers' solutions test to
+// those who ask for it with --test solutions.
+#[cfg(not(test))]
+fn cargo_test(colour: &str) -> Command {
+    let mut command = Command::new("cargo");
+    command
         .arg("test")
         .arg("-q")
-        .status()
-        .unwrap()
-        .success()
+        .arg("--bin")
+        .arg("koans")
+        .arg("--color")
+        .arg(colour)
+        .current_dir(paths::root());
+    command
 }
 
+#[cfg(not(test))]
+fn said(output: &Output) -> String {
+    let mut said = String::from_utf8_lossy(&output.stderr).into_owned();
+    said.push_str(&String::from_utf8_lossy(&output.stdout));
+    said
+}
+
+// Laid out by build.rs from the learner's progress.
 #[cfg(test)]
-mod path_to_enlightenment;
+mod path_to_enlightenment {
+    include!(concat!(env!("OUT_DIR"), "/path_to_enlightenment.rs"));
+}
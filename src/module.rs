@@ -0,0 +1,27 @@
+// Each koan file is walked in a module of its own, named for the file, so
+// that its tests and imports do not collide with those of any other. This is
+// shared with build.rs, which lays out the path.
+
+// Words Rust keeps for itself, which can only name a module when written
+// raw, as in r#struct.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static", "struct",
+    "super", "trait", "true", "try", "type", "typeof", "union", "unsafe", "unsized", "use", "virtual", "where",
+    "while",
+];
+
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
+
+// The identifier of the module a koan file is walked in, such as vec or
+// r#struct.
+pub fn ident(name: &str) -> String {
+    if is_keyword(name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
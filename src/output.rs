@@ -0,0 +1,100 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+// How the runner dresses what it says. Colour is only worn when speaking to a
+// terminal that can show it, and never when NO_COLOR is set.
+#[derive(Clone, Copy)]
+pub enum Style {
+    // The path goes on.
+    Pass,
+    // The path is blocked.
+    Fail,
+    // An aside, such as a hint.
+    Comment,
+    // Something to look at closely, such as a koan's name or a blank.
+    Highlight,
+}
+
+impl Style {
+    fn code(self) -> &'static str {
+        match self {
+            Style::Pass => "32",
+            Style::Fail => "31",
+            Style::Comment => "2",
+            Style::Highlight => "1;33",
+        }
+    }
+}
+
+static STDOUT_COLOUR: OnceLock<bool> = OnceLock::new();
+static STDERR_COLOUR: OnceLock<bool> = OnceLock::new();
+
+// Styles text bound for stdout.
+pub fn paint(style: Style, text: &str) -> String {
+    let colour = *STDOUT_COLOUR.get_or_init(|| wants_colour(io::stdout().is_terminal()));
+    dress(colour, style, text)
+}
+
+// Styles text bound for stderr.
+pub fn paint_err(style: Style, text: &str) -> String {
+    let colour = *STDERR_COLOUR.get_or_init(|| wants_colour(io::stderr().is_terminal()));
+    dress(colour, style, text)
+}
+
+fn dress(colour: bool, style: Style, text: &str) -> String {
+    if colour {
+        format!("\x1b[{}m{}\x1b[0m", style.code(), text)
+    } else {
+        text.to_string()
+    }
+}
+
+// What to pass to cargo's --color when it speaks to the learner directly.
+// Cargo finds its own way with terminals, but does not know NO_COLOR.
+pub fn cargo_colour() -> &'static str {
+    if no_color() {
+        "never"
+    } else {
+        "auto"
+    }
+}
+
+// What to pass to cargo's --color when the runner keeps what cargo says and
+// passes it on to stderr itself.
+pub fn captured_cargo_colour() -> &'static str {
+    if *STDERR_COLOUR.get_or_init(|| wants_colour(io::stderr().is_terminal())) {
+        "always"
+    } else {
+        "never"
+    }
+}
+
+// Takes the colour back out of what cargo said, so that it may be read.
+pub fn unpaint(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the escape sequence through to its final letter
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+fn wants_colour(is_terminal: bool) -> bool {
+    let dumb = env::var_os("TERM").is_some_and(|term| term == "dumb");
+    is_terminal && !no_color() && !dumb
+}
+
+// See https://no-color.org/
+fn no_color() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
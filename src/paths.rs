@@ -0,0 +1,99 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use error::PathError;
+
+static ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+// Finds the root of the repository, so that the runner may be started from
+// anywhere within it. Cargo says where the manifest is when it runs us;
+// failing that, we climb from the current directory until we find it.
+pub fn find_root() -> Result<(), PathError> {
+    let from_cargo = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    let root = match from_cargo {
+        Some(ref dir) if is_root(dir) => dir.clone(),
+        _ => climb()?,
+    };
+    ROOT.get_or_init(|| root);
+    Ok(())
+}
+
+fn climb() -> Result<PathBuf, PathError> {
+    let start = env::current_dir().map_err(|error| PathError::io("find the current directory", error))?;
+    let mut found_crate = false;
+
+    for dir in start.ancestors() {
+        if is_root(dir) {
+            return Ok(dir.to_path_buf());
+        }
+        found_crate = found_crate || dir.join("Cargo.toml").is_file();
+    }
+
+    if found_crate {
+        Err(PathError::MissingManifest)
+    } else {
+        Err(PathError::WrongDirectory)
+    }
+}
+
+fn is_root(dir: &Path) -> bool {
+    dir.join("Cargo.toml").is_file() && dir.join("src").join("koans.txt").is_file()
+}
+
+pub fn root() -> &'static Path {
+    ROOT.get().expect("the root is found before the path is walked")
+}
+
+// src/koans.txt, which lists every koan in order.
+pub fn manifest() -> PathBuf {
+    root().join("src").join("koans.txt")
+}
+
+// The support the koans are compiled with, such as koan_assert!.
+pub fn harness() -> PathBuf {
+    root().join("src").join("harness.rs")
+}
+
+pub fn koan(name: &str) -> PathBuf {
+    root().join("src").join("koans").join(format!("{}.rs", name))
+}
+
+// The reference solution to a koan file, such as solutions/vec.rs.
+pub fn solution(name: &str) -> PathBuf {
+    root().join("solutions").join(format!("{}.rs", name))
+}
+
+// The project's own test, which walks the path with the solutions.
+pub fn solutions_test() -> PathBuf {
+    root().join("tests").join("solutions.rs")
+}
+
+// A practice koan the learner is working on, such as practice/addition.rs.
+pub fn practice(name: &str) -> PathBuf {
+    root().join("practice").join(format!("{}.rs", name))
+}
+
+// The koans' own word on a compiler error, such as src/notes/E0382.txt.
+pub fn note(code: &str) -> PathBuf {
+    root().join("src").join("notes").join(format!("{}.txt", code))
+}
+
+// The translations into a language, such as src/locales/es.
+pub fn locale(lang: &str) -> PathBuf {
+    root().join("src").join("locales").join(lang)
+}
+
+// Where the path was laid out before the learner's progress was kept in
+// .progress.
+pub fn old_path_to_enlightenment() -> PathBuf {
+    root().join("src").join("path_to_enlightenment.rs")
+}
+
+pub fn progress() -> PathBuf {
+    root().join(".progress")
+}
+
+pub fn sandbox(name: &str) -> PathBuf {
+    root().join("target").join("sandbox").join(name)
+}
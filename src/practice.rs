@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::PathBuf;
+
+use error::PathError;
+use paths;
+
+// The practice koans for the modules the learner has completed, as ROUND,
+// filled in by build.rs from src/templates/ with the SEED in .progress, or
+// with a fresh one if none has yet been recorded.
+include!(concat!(env!("OUT_DIR"), "/practice.rs"));
+
+// The seed the practice koans were filled in with.
+pub fn seed() -> u64 {
+    SEED
+}
+
+// The modules there are practice koans for.
+pub fn modules() -> Vec<&'static str> {
+    ROUND.iter().map(|&(module, _)| module).collect()
+}
+
+// The practice koans written out for the learner to solve, if a round has
+// begun.
+pub fn begun() -> Vec<PathBuf> {
+    modules()
+        .into_iter()
+        .map(paths::practice)
+        .filter(|file| file.is_file())
+        .collect()
+}
+
+// Writes out a fresh round of practice koans under practice/.
+pub fn begin() -> Result<Vec<PathBuf>, PathError> {
+    let dir = paths::root().join("practice");
+    fs::create_dir_all(&dir).map_err(|error| PathError::io(&format!("create {}", dir.display()), error))?;
+
+    let mut files = Vec::new();
+    for &(module, koans) in ROUND {
+        let file = paths::practice(module);
+        fs::write(&file, koans).map_err(|error| PathError::io(&format!("write {}", file.display()), error))?;
+        files.push(file);
+    }
+    Ok(files)
+}
+
+// Clears away a finished round, so that the next may be written.
+pub fn finish(files: &[PathBuf]) -> Result<(), PathError> {
+    for file in files {
+        fs::remove_file(file).map_err(|error| PathError::io(&format!("remove {}", file.display()), error))?;
+    }
+    Ok(())
+}
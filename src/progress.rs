@@ -0,0 +1,326 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use error::PathError;
+use paths;
+
+// What the learner has done with one entry of src/koans.txt.
+// Times are seconds since the epoch.
+pub struct Entry {
+    pub name: String,
+    pub unlocked: u64,
+    pub completed: Option<u64>,
+    pub attempts: u32,
+    pub hints: u32,
+}
+
+impl Entry {
+    pub fn seconds_spent(&self) -> Option<u64> {
+        self.completed.map(|completed| completed.saturating_sub(self.unlocked))
+    }
+}
+
+// A milestone the learner has reached, by its id in achievements.rs.
+pub struct Achievement {
+    pub id: String,
+    pub earned: u64,
+}
+
+// The days in a row on which the learner has walked the path. Days are
+// counted since the epoch.
+#[derive(Default)]
+pub struct Streak {
+    pub days: u32,
+    pub last: u64,
+}
+
+// Lines of .progress that record the journey as a whole rather than a koan.
+// Their names cannot be mistaken for a koan's.
+const STREAK: &str = "streak:";
+const ACHIEVEMENT: &str = "achievement:";
+const PRACTICE: &str = "practice:";
+
+// The learner's journey so far, one line per koan in the order they were met,
+// then the learner's streak, the seed practice koans are filled in with, and
+// each achievement earned:
+//     vec unlocked=1445000000 completed=1445000600 attempts=4 hints=1
+//     streak: days=3 last=16724
+//     practice: seed=1445086400
+//     achievement:first_step earned=1445000600
+pub struct Progress {
+    pub entries: Vec<Entry>,
+    pub streak: Streak,
+    // Read by build.rs as well, which fills in the practice koans
+    pub seed: u64,
+    pub achievements: Vec<Achievement>,
+}
+
+impl Progress {
+    fn new() -> Progress {
+        Progress {
+            entries: Vec::new(),
+            streak: Streak::default(),
+            seed: 0,
+            achievements: Vec::new(),
+        }
+    }
+
+    pub fn load() -> Result<Progress, PathError> {
+        let mut progress = Progress::read()?;
+        progress.adopt_old_path()?;
+        Ok(progress)
+    }
+
+    fn read() -> Result<Progress, PathError> {
+        let file = match File::open(paths::progress()) {
+            Ok(file) => file,
+            Err(ref error) if error.kind() == ErrorKind::NotFound => return Ok(Progress::new()),
+            Err(error) => return Err(PathError::io("read your progress", error)),
+        };
+        Progress::parse(BufReader::new(file))
+    }
+
+    // Reads the learner's journey from lines written as .progress keeps them.
+    fn parse<R: BufRead>(reader: R) -> Result<Progress, PathError> {
+        let mut progress = Progress::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line.map_err(|error| PathError::io("read your progress", error))?;
+            let corrupt = |reason: String| PathError::CorruptProgress {
+                file: ".progress".to_string(),
+                line: index + 1,
+                reason,
+            };
+
+            let mut fields = line.split_whitespace();
+            let name = match fields.next() {
+                Some(name) => name.to_string(),
+                None => continue,
+            };
+            let mut pairs = Vec::new();
+            for field in fields {
+                let mut pair = field.splitn(2, '=');
+                let (key, value) = match (pair.next(), pair.next()) {
+                    (Some(key), Some(value)) => (key, value),
+                    _ => return Err(corrupt(format!("`{}` is not a key=value pair", field))),
+                };
+                let number = value
+                    .parse::<u64>()
+                    .map_err(|_| corrupt(format!("{} should be a number, not `{}`", key, value)))?;
+                pairs.push((key, number));
+            }
+            let unknown = |key: &str| corrupt(format!("`{}` is not something the path records", key));
+
+            if name == STREAK {
+                for (key, number) in pairs {
+                    match key {
+                        "days" => progress.streak.days = number as u32,
+                        "last" => progress.streak.last = number,
+                        _ => return Err(unknown(key)),
+                    }
+                }
+            } else if name == PRACTICE {
+                for (key, number) in pairs {
+                    match key {
+                        "seed" => progress.seed = number,
+                        _ => return Err(unknown(key)),
+                    }
+                }
+            } else if let Some(id) = name.strip_prefix(ACHIEVEMENT) {
+                let mut achievement = Achievement {
+                    id: id.to_string(),
+                    earned: 0,
+                };
+                for (key, number) in pairs {
+                    match key {
+                        "earned" => achievement.earned = number,
+                        _ => return Err(unknown(key)),
+                    }
+                }
+                progress.achievements.push(achievement);
+            } else {
+                let mut entry = Entry {
+                    name,
+                    unlocked: 0,
+                    completed: None,
+                    attempts: 0,
+                    hints: 0,
+                };
+                for (key, number) in pairs {
+                    match key {
+                        "unlocked" => entry.unlocked = number,
+                        "completed" => entry.completed = Some(number),
+                        "attempts" => entry.attempts = number as u32,
+                        "hints" => entry.hints = number as u32,
+                        _ => return Err(unknown(key)),
+                    }
+                }
+                progress.entries.push(entry);
+            }
+        }
+
+        Ok(progress)
+    }
+
+    // Learners who set out before .progress was kept have their path in
+    // src/path_to_enlightenment.rs instead. Carry it over, so that nobody
+    // has to begin again.
+    fn adopt_old_path(&mut self) -> Result<(), PathError> {
+        let old_path = paths::old_path_to_enlightenment();
+        let file = match File::open(&old_path) {
+            Ok(file) => file,
+            Err(_) => return Ok(()),
+        };
+
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|error| PathError::io("read src/path_to_enlightenment.rs", error))?;
+            if let Some(name) = line.split('"').nth(1) {
+                self.entry_mut(name);
+            }
+        }
+
+        self.save()?;
+        fs::remove_file(&old_path)
+            .map_err(|error| PathError::io("remove src/path_to_enlightenment.rs", error))
+    }
+
+    pub fn save(&self) -> Result<(), PathError> {
+        self.write().map_err(|error| PathError::io("record your progress", error))
+    }
+
+    fn write(&self) -> io::Result<()> {
+        let mut file = File::create(paths::progress())?;
+        for entry in &self.entries {
+            write!(file, "{} unlocked={}", entry.name, entry.unlocked)?;
+            if let Some(completed) = entry.completed {
+                write!(file, " completed={}", completed)?;
+            }
+            writeln!(file, " attempts={} hints={}", entry.attempts, entry.hints)?;
+        }
+        if self.streak.days > 0 {
+            writeln!(file, "{} days={} last={}", STREAK, self.streak.days, self.streak.last)?;
+        }
+        if self.seed > 0 {
+            writeln!(file, "{} seed={}", PRACTICE, self.seed)?;
+        }
+        for achievement in &self.achievements {
+            writeln!(file, "{}{} earned={}", ACHIEVEMENT, achievement.id, achievement.earned)?;
+        }
+        Ok(())
+    }
+
+    // Looks up a koan, starting its clock if the learner has not met it before.
+    pub fn entry_mut(&mut self, name: &str) -> &mut Entry {
+        match self.entries.iter().position(|entry| entry.name == name) {
+            Some(index) => &mut self.entries[index],
+            None => {
+                self.entries.push(Entry {
+                    name: name.to_string(),
+                    unlocked: now(),
+                    completed: None,
+                    attempts: 0,
+                    hints: 0,
+                });
+                self.entries.last_mut().unwrap()
+            }
+        }
+    }
+
+    // Counts today towards the learner's streak.
+    pub fn walk_today(&mut self) {
+        let today = now() / SECONDS_PER_DAY;
+        if self.streak.last + 1 == today {
+            self.streak.days += 1;
+        } else if self.streak.last != today {
+            self.streak.days = 1;
+        }
+        self.streak.last = today;
+    }
+
+    pub fn has_earned(&self, id: &str) -> bool {
+        self.achievements.iter().any(|achievement| achievement.id == id)
+    }
+
+    pub fn completed(&self) -> Vec<&Entry> {
+        self.entries.iter().filter(|entry| entry.completed.is_some()).collect()
+    }
+}
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(text: &str) -> Progress {
+        match Progress::parse(text.as_bytes()) {
+            Ok(progress) => progress,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    #[test]
+    fn entries_are_read_line_by_line() {
+        let progress = progress(
+            "\
+the_truth unlocked=10 completed=70 attempts=2 hints=1
+vec unlocked=80 attempts=0 hints=0
+",
+        );
+        assert_eq!(progress.entries.len(), 2);
+        assert_eq!(progress.entries[0].name, "the_truth");
+        assert_eq!(progress.entries[0].seconds_spent(), Some(60));
+        assert_eq!(progress.entries[0].attempts, 2);
+        assert_eq!(progress.entries[0].hints, 1);
+        assert_eq!(progress.entries[1].completed, None);
+    }
+
+    #[test]
+    fn progress_that_cannot_be_understood_says_where() {
+        let lines = ["vec unlocked", "vec unlocked=soon", "vec rank=1"];
+        for text in &lines {
+            match Progress::parse(format!("the_truth unlocked=1\n{}\n", text).as_bytes()) {
+                Err(PathError::CorruptProgress { line, .. }) => assert_eq!(line, 2),
+                _ => panic!("`{}` was understood", text),
+            }
+        }
+    }
+
+    #[test]
+    fn the_streak_and_achievements_are_read_apart_from_the_koans() {
+        let progress = progress(
+            "\
+the_truth unlocked=10 completed=70 attempts=2 hints=1
+streak: days=3 last=16724
+achievement:first_step earned=70
+",
+        );
+        assert_eq!(progress.entries.len(), 1);
+        assert_eq!(progress.streak.days, 3);
+        assert_eq!(progress.streak.last, 16724);
+        assert!(progress.has_earned("first_step"));
+        assert_eq!(progress.achievements[0].earned, 70);
+
+        match Progress::parse(&b"streak: weeks=1\n"[..]) {
+            Err(PathError::CorruptProgress { line, .. }) => assert_eq!(line, 1),
+            _ => panic!("`streak: weeks=1` was understood"),
+        }
+    }
+
+    #[test]
+    fn the_practice_seed_is_read_apart_from_the_koans() {
+        let recorded = progress("the_truth unlocked=10\npractice: seed=42\n");
+        assert_eq!(recorded.entries.len(), 1);
+        assert_eq!(recorded.seed, 42);
+
+        // No round has yet been filled in
+        assert_eq!(progress("the_truth unlocked=10\n").seed, 0);
+    }
+}
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use error::PathError;
+use koan;
+use paths;
+use sandbox::Sandbox;
+
+// A suggestion from clippy on how a solved koan might be written better.
+pub struct Advice {
+    pub module: String,
+    // The koan the advice falls within, if it falls within one at all.
+    pub koan: Option<String>,
+    pub line: usize,
+    // What clippy said, from its headline to the blank line that ends it.
+    pub text: String,
+}
+
+// Runs clippy over the given koan files, which the learner has solved, and
+// gathers its advice on each.
+pub fn seek_advice(modules: &[String]) -> Result<Vec<Advice>, PathError> {
+    let sandbox = Sandbox::new("refactor")?;
+    let files: Vec<PathBuf> = modules.iter().map(|module| paths::koan(module)).collect();
+    sandbox.walk(&files)?;
+
+    let (linted, said) = sandbox.clippy()?;
+    if !linted {
+        return Err(PathError::Usage(if said.contains("no such command") {
+            text!("no-clippy")
+        } else {
+            format!("{}\n{}", text!("clippy-failed"), said)
+        }));
+    }
+
+    let mut advice = Vec::new();
+    for module in modules {
+        let koans = koan::koans_in(&koan::read_source(module)?);
+        let site = format!("src/koans/{}.rs:", module);
+
+        for text in said.split("\n\n").filter(|text| text.starts_with("warning")) {
+            let line = match text
+                .lines()
+                .filter(|line| line.trim_start().starts_with("-->"))
+                .find_map(|line| {
+                    let start = line.find(&site)? + site.len();
+                    line[start..].split(':').next()?.parse().ok()
+                }) {
+                Some(line) => line,
+                None => continue,
+            };
+
+            advice.push(Advice {
+                module: module.clone(),
+                koan: koans
+                    .iter()
+                    .find(|koan| koan.contains_line(line))
+                    .map(|koan| koan.name.clone()),
+                line,
+                text: text.trim_end().to_string(),
+            });
+        }
+    }
+
+    Ok(advice)
+}
@@ -0,0 +1,162 @@
+use std::fmt::Write;
+
+use error::PathError;
+use koan;
+use progress::{Entry, Progress};
+
+pub enum Format {
+    Markdown,
+    Html,
+    Json,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "markdown" | "md" => Some(Format::Markdown),
+            "html" => Some(Format::Html),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+// A completed koan together with the learner's solution to it.
+struct Solved<'a> {
+    entry: &'a Entry,
+    source: String,
+}
+
+// Renders every completed koan, with the learner's solution, so that it can be
+// handed to a mentor for review.
+pub fn render(format: &Format, progress: &Progress, total: usize) -> Result<String, PathError> {
+    let mut solved = Vec::new();
+    for entry in progress.completed() {
+        let source = koan::read_source(&entry.name)?;
+        solved.push(Solved { entry, source });
+    }
+
+    Ok(match *format {
+        Format::Markdown => markdown(&solved, total),
+        Format::Html => html(&solved, total),
+        Format::Json => json(&solved, total),
+    })
+}
+
+fn markdown(solved: &[Solved], total: usize) -> String {
+    let mut out = String::new();
+    writeln!(out, "# The Path to Enlightenment\n").unwrap();
+    writeln!(out, "Koans completed: {} of {}\n", solved.len(), total).unwrap();
+
+    for koan in solved {
+        writeln!(out, "## {}\n", koan.entry.name).unwrap();
+        writeln!(out, "- Time spent: {}", duration(koan.entry)).unwrap();
+        writeln!(out, "- Attempts: {}", koan.entry.attempts).unwrap();
+        writeln!(out, "- Hints used: {}\n", koan.entry.hints).unwrap();
+        writeln!(out, "```rust\n{}```\n", with_newline(&koan.source)).unwrap();
+    }
+
+    out
+}
+
+fn html(solved: &[Solved], total: usize) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>The Path to Enlightenment</title>\n<style>\n");
+    out.push_str("body { font-family: sans-serif; max-width: 50em; margin: 2em auto; color: #222; }\n");
+    out.push_str("pre { background: #f4f4f4; padding: 1em; overflow-x: auto; }\n");
+    out.push_str("dt { font-weight: bold; float: left; clear: left; width: 8em; }\n");
+    out.push_str("dd { margin-left: 8em; }\n");
+    out.push_str("</style>\n</head>\n<body>\n");
+    out.push_str("<h1>The Path to Enlightenment</h1>\n");
+    writeln!(out, "<p>Koans completed: {} of {}</p>", solved.len(), total).unwrap();
+
+    for koan in solved {
+        writeln!(out, "<h2>{}</h2>\n<dl>", escape_html(&koan.entry.name)).unwrap();
+        writeln!(out, "<dt>Time spent</dt><dd>{}</dd>", duration(koan.entry)).unwrap();
+        writeln!(out, "<dt>Attempts</dt><dd>{}</dd>", koan.entry.attempts).unwrap();
+        writeln!(out, "<dt>Hints used</dt><dd>{}</dd>\n</dl>", koan.entry.hints).unwrap();
+        writeln!(out, "<pre><code>{}</code></pre>", escape_html(&koan.source)).unwrap();
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn json(solved: &[Solved], total: usize) -> String {
+    let koans: Vec<String> = solved
+        .iter()
+        .map(|koan| {
+            format!(
+                "    {{\"name\": {}, \"seconds_spent\": {}, \"attempts\": {}, \"hints_used\": {}, \"solution\": {}}}",
+                escape_json(&koan.entry.name),
+                koan.entry.seconds_spent().unwrap_or(0),
+                koan.entry.attempts,
+                koan.entry.hints,
+                escape_json(&koan.source)
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"completed\": {},\n  \"total\": {},\n  \"koans\": [\n{}\n  ]\n}}\n",
+        solved.len(),
+        total,
+        koans.join(",\n")
+    )
+}
+
+fn duration(entry: &Entry) -> String {
+    let seconds = entry.seconds_spent().unwrap_or(0);
+    match (seconds / 3600, seconds / 60 % 60, seconds % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {}s", m, s),
+        (h, m, _) => format!("{}h {}m", h, m),
+    }
+}
+
+fn with_newline(source: &str) -> String {
+    if source.ends_with('\n') {
+        source.to_string()
+    } else {
+        format!("{}\n", source)
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_json(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(escape_json("plain"), "\"plain\"");
+        assert_eq!(escape_json("say \"hi\"\\"), "\"say \\\"hi\\\"\\\\\"");
+        assert_eq!(escape_json("a\nb\tc\r"), "\"a\\nb\\tc\\r\"");
+        assert_eq!(escape_json("\u{1}"), "\"\\u0001\"");
+        assert_eq!(escape_json("禅"), "\"禅\"");
+    }
+}
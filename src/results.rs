@@ -0,0 +1,326 @@
+use std::fmt::Write;
+
+use error::PathError;
+use explain;
+use koan::{self, Koan};
+use paths;
+use sandbox::Sandbox;
+
+pub enum Format {
+    JUnit,
+    Tap,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "junit" => Some(Format::JUnit),
+            "tap" => Some(Format::Tap),
+            _ => None,
+        }
+    }
+}
+
+pub enum Outcome {
+    Passed,
+    // The koan ran and panicked, with its captured output.
+    Failed(String),
+    // The koan's module did not compile, so the koan could not run.
+    Errored(String),
+    // The koan has not yet been unlocked.
+    Skipped,
+}
+
+pub struct TestCase {
+    pub module: String,
+    pub name: String,
+    pub outcome: Outcome,
+}
+
+impl TestCase {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.outcome, Outcome::Failed(_) | Outcome::Errored(_))
+    }
+}
+
+// Walks the path without stopping at the first failure, and reports how each
+// koan of every module in the manifest fared. Each unlocked module is
+// compiled on its own, so that an error in one counts only against its own
+// koans.
+pub fn gather(modules: &[String], unlocked: &[String]) -> Result<Vec<TestCase>, PathError> {
+    let sandbox = Sandbox::new("results")?;
+    let mut cases = Vec::new();
+
+    for module in modules {
+        let koans = koan::koans_in(&koan::read_source(module)?);
+        let output = if unlocked.contains(module) {
+            sandbox.walk(&[paths::koan(module)])?;
+            Some(sandbox.test_verbosely()?)
+        } else {
+            None
+        };
+
+        for found in koans {
+            let outcome = match output {
+                None => Outcome::Skipped,
+                Some(ref output) => {
+                    let stdout = String::from_utf8_lossy(&output.stdout);
+                    if stdout.contains("test result:") {
+                        outcome_of(&stdout, &koan::test_name(module, &found.name))
+                    } else {
+                        Outcome::Errored(compiler_errors(&String::from_utf8_lossy(&output.stderr), module, &found))
+                    }
+                }
+            };
+            cases.push(TestCase {
+                module: module.clone(),
+                name: found.name,
+                outcome,
+            });
+        }
+    }
+
+    Ok(cases)
+}
+
+fn outcome_of(stdout: &str, full_name: &str) -> Outcome {
+    // should_panic koans are listed as "test name - should panic ... ok"
+    let status_line = format!("test {} ", full_name);
+
+    match stdout.lines().find(|line| line.starts_with(&status_line)) {
+        Some(line) if line.ends_with("ok") => Outcome::Passed,
+        Some(_) => Outcome::Failed(captured_output(stdout, full_name)),
+        None => Outcome::Errored(format!("{} did not run", full_name)),
+    }
+}
+
+// The errors the compiler raised within a koan of a module that did not
+// compile. A koan the compiler did not point at is only said to have been
+// kept from running.
+fn compiler_errors(stderr: &str, module: &str, koan: &Koan) -> String {
+    let within: Vec<String> = explain::diagnostics(stderr, module)
+        .into_iter()
+        .filter(|diagnostic| koan::error_line(&diagnostic.text, module).is_some_and(|line| koan.contains_line(line)))
+        .map(|diagnostic| diagnostic.text.trim_end().to_string())
+        .collect();
+    if within.is_empty() {
+        text!("module-did-not-compile", module = module)
+    } else {
+        within.join("\n\n")
+    }
+}
+
+// The output libtest captured for a failed koan, which it prints under a
+// "---- name stdout ----" header.
+fn captured_output(stdout: &str, full_name: &str) -> String {
+    let header = format!("---- {} stdout ----", full_name);
+    let lines = stdout.lines().skip_while(|line| *line != header).skip(1);
+    let mut captured = Vec::new();
+    for line in lines {
+        if line.starts_with("---- ") || line == "failures:" {
+            break;
+        }
+        captured.push(line);
+    }
+    captured.join("\n").trim().to_string()
+}
+
+pub fn render(format: &Format, cases: &[TestCase]) -> String {
+    match *format {
+        Format::JUnit => junit(cases),
+        Format::Tap => tap(cases),
+    }
+}
+
+fn junit(cases: &[TestCase]) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        out,
+        "<testsuites name=\"koans\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+        cases.len(),
+        count(cases, |outcome| matches!(*outcome, Outcome::Failed(_))),
+        count(cases, |outcome| matches!(*outcome, Outcome::Errored(_))),
+        count(cases, |outcome| matches!(*outcome, Outcome::Skipped))
+    )
+    .unwrap();
+
+    let mut start = 0;
+    while start < cases.len() {
+        let module = &cases[start].module;
+        let end = start + cases[start..].iter().take_while(|case| case.module == *module).count();
+        let suite = &cases[start..end];
+
+        writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+            escape_xml(module),
+            suite.len(),
+            count(suite, |outcome| matches!(*outcome, Outcome::Failed(_))),
+            count(suite, |outcome| matches!(*outcome, Outcome::Errored(_))),
+            count(suite, |outcome| matches!(*outcome, Outcome::Skipped))
+        )
+        .unwrap();
+        for case in suite {
+            write!(
+                out,
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                escape_xml(&case.module),
+                escape_xml(&case.name)
+            )
+            .unwrap();
+            match case.outcome {
+                Outcome::Passed => out.push_str("/>\n"),
+                Outcome::Failed(ref message) => writeln!(
+                    out,
+                    ">\n      <failure message=\"koan failed\">{}</failure>\n    </testcase>",
+                    escape_xml(message)
+                )
+                .unwrap(),
+                Outcome::Errored(ref message) => writeln!(
+                    out,
+                    ">\n      <error message=\"module did not compile\">{}</error>\n    </testcase>",
+                    escape_xml(message)
+                )
+                .unwrap(),
+                Outcome::Skipped => out.push_str(">\n      <skipped message=\"locked\"/>\n    </testcase>\n"),
+            }
+        }
+        out.push_str("  </testsuite>\n");
+
+        start = end;
+    }
+
+    out.push_str("</testsuites>\n");
+    out
+}
+
+fn tap(cases: &[TestCase]) -> String {
+    let mut out = String::new();
+    out.push_str("TAP version 13\n");
+    writeln!(out, "1..{}", cases.len()).unwrap();
+
+    for (index, case) in cases.iter().enumerate() {
+        let number = index + 1;
+        let description = format!("{}::{}", case.module, case.name);
+        match case.outcome {
+            Outcome::Passed => writeln!(out, "ok {} - {}", number, description).unwrap(),
+            Outcome::Skipped => writeln!(out, "ok {} - {} # SKIP locked", number, description).unwrap(),
+            Outcome::Failed(ref message) | Outcome::Errored(ref message) => {
+                writeln!(out, "not ok {} - {}", number, description).unwrap();
+                out.push_str("  ---\n  message: |\n");
+                for line in message.lines() {
+                    writeln!(out, "    {}", line).unwrap();
+                }
+                out.push_str("  ...\n");
+            }
+        }
+    }
+
+    out
+}
+
+fn count<F: Fn(&Outcome) -> bool>(cases: &[TestCase], predicate: F) -> usize {
+    cases.iter().filter(|case| predicate(&case.outcome)).count()
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIBTEST: &str = "\
+running 4 tests
+test path_to_enlightenment::array::out_of_index - should panic ... ok
+test path_to_enlightenment::vec::insert ... ok
+test path_to_enlightenment::vec::insert_many ... FAILED
+test path_to_enlightenment::string::slicing_the_byte - should panic ... FAILED
+
+failures:
+
+---- path_to_enlightenment::vec::insert_many stdout ----
+thread 'path_to_enlightenment::vec::insert_many' panicked at src/koans/vec.rs:9:5:
+The koan insert_many has not yet been solved
+
+---- path_to_enlightenment::string::slicing_the_byte stdout ----
+note: test did not panic as expected
+
+failures:
+    path_to_enlightenment::vec::insert_many
+    path_to_enlightenment::string::slicing_the_byte
+
+test result: FAILED. 2 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out
+";
+
+    #[test]
+    fn outcomes_are_read_from_libtest() {
+        let outcome = |koan: &str| outcome_of(LIBTEST, &format!("path_to_enlightenment::{}", koan));
+
+        assert!(matches!(outcome("array::out_of_index"), Outcome::Passed));
+        assert!(matches!(outcome("vec::insert"), Outcome::Passed));
+        match outcome("vec::insert_many") {
+            Outcome::Failed(said) => assert!(said.ends_with("has not yet been solved"), "{}", said),
+            _ => panic!("insert_many did not fail"),
+        }
+        match outcome("string::slicing_the_byte") {
+            Outcome::Failed(said) => assert_eq!(said, "note: test did not panic as expected"),
+            _ => panic!("slicing_the_byte did not fail"),
+        }
+        assert!(matches!(outcome("vec::missing"), Outcome::Errored(_)));
+    }
+
+    #[test]
+    fn captured_output_stops_at_the_next_koan() {
+        let said = captured_output(LIBTEST, "path_to_enlightenment::vec::insert_many");
+        assert_eq!(said.lines().count(), 2);
+        assert!(!said.contains("slicing_the_byte"));
+        assert_eq!(captured_output(LIBTEST, "path_to_enlightenment::vec::insert"), "");
+    }
+
+    const UNCOMPILED: &str = "\
+   Compiling koans-results v0.1.0 (/root/crate/target/sandbox/results)
+error[E0425]: cannot find value `__` in this scope
+  --> src/koans/vec.rs:4:13
+   |
+4  |     assert!(__);
+   |             ^^ not found in this scope
+
+error[E0308]: mismatched types
+  --> src/koans/vec.rs:10:22
+   |
+10 |     let count: u8 = \"three\";
+   |                --   ^^^^^^^ expected `u8`, found `&str`
+
+error: could not compile `koans-results` (bin \"koans-results\" test) due to 2 previous errors
+";
+
+    fn koan_at(name: &str, line: usize) -> Koan {
+        Koan {
+            name: name.to_string(),
+            comment: String::new(),
+            source: "#[test]\nfn koan() {\n    assert!(__);\n}\n".to_string(),
+            line,
+        }
+    }
+
+    #[test]
+    fn compiler_errors_are_those_within_the_koan() {
+        let said = compiler_errors(UNCOMPILED, "vec", &koan_at("creation", 2));
+        assert!(said.starts_with("error[E0425]"), "{}", said);
+        assert!(!said.contains("E0308"), "{}", said);
+
+        let said = compiler_errors(UNCOMPILED, "vec", &koan_at("counting", 8));
+        assert!(said.starts_with("error[E0308]"), "{}", said);
+        assert!(!said.contains("E0425"), "{}", said);
+
+        let said = compiler_errors(UNCOMPILED, "vec", &koan_at("insert", 14));
+        assert_eq!(said.lines().count(), 1);
+        assert!(said.contains("vec"), "{}", said);
+    }
+}
@@ -0,0 +1,167 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Output};
+
+use error::PathError;
+use module;
+use output;
+use paths;
+
+// A throwaway crate under target/ in which koans can be compiled and tested
+// without disturbing the learner's own path.
+pub struct Sandbox {
+    dir: PathBuf,
+}
+
+impl Sandbox {
+    pub fn new(name: &str) -> Result<Sandbox, PathError> {
+        let dir = paths::sandbox(name);
+        Sandbox::build(&dir, name).map_err(|error| PathError::io("build the sandbox", error))?;
+        Ok(Sandbox { dir })
+    }
+
+    fn build(dir: &Path, name: &str) -> io::Result<()> {
+        fs::create_dir_all(dir.join("src"))?;
+
+        let mut manifest = File::create(dir.join("Cargo.toml"))?;
+        writeln!(manifest, "[package]")?;
+        writeln!(manifest, "name = \"koans-{}\"", name)?;
+        writeln!(manifest, "version = \"0.0.0\"")?;
+        writeln!(manifest, "edition = \"2015\"")?;
+        writeln!(manifest)?;
+        writeln!(manifest, "[workspace]")
+    }
+
+    // Places the given koan files on the sandbox's path, in order, each in a
+    // module named for it, replacing whatever was there before.
+    pub fn walk(&self, koans: &[PathBuf]) -> Result<(), PathError> {
+        let mut path = String::new();
+        for koan in koans {
+            let name = koan.file_stem().and_then(|name| name.to_str()).unwrap_or_default();
+            let koan = fs::canonicalize(koan)
+                .map_err(|error| PathError::io(&format!("find {}", koan.display()), error))?;
+            path.push_str(&format!("mod {} {{\n    include!({:?});\n}}\n", module::ident(name), koan));
+        }
+        self.enter(&path)
+    }
+
+    // Keeps a file of koans within the sandbox, such as one rewritten to be
+    // walked on its own, returning where it is kept as the compiler will
+    // name it.
+    pub fn keep(&self, name: &str, source: &str) -> Result<PathBuf, PathError> {
+        let file = self.dir.join("koans").join(name);
+        fs::create_dir_all(self.dir.join("koans"))
+            .and_then(|_| fs::write(&file, source))
+            .and_then(|_| fs::canonicalize(&file))
+            .map_err(|error| PathError::io(&format!("write {}", file.display()), error))
+    }
+
+    // Places the given source alone on the sandbox's path.
+    pub fn enter(&self, source: &str) -> Result<(), PathError> {
+        self.write_main(source)
+            .map_err(|error| PathError::io("set the sandbox's path", error))
+    }
+
+    fn write_main(&self, source: &str) -> io::Result<()> {
+        let mut main = File::create(self.dir.join("src").join("main.rs"))?;
+        writeln!(main, "fn main() {{}}")?;
+        writeln!(main)?;
+        writeln!(main, "#[cfg(test)]")?;
+        writeln!(main, "#[path = {:?}]", paths::harness())?;
+        writeln!(main, "#[macro_use]")?;
+        writeln!(main, "mod harness;")?;
+        writeln!(main)?;
+        writeln!(main, "#[cfg(test)]")?;
+        writeln!(main, "mod path_to_enlightenment {{")?;
+        main.write_all(source.as_bytes())?;
+        writeln!(main, "}}")?;
+        Ok(())
+    }
+
+    pub fn test(&self) -> Result<ExitStatus, PathError> {
+        self.cargo_test()
+            .arg("--color")
+            .arg(output::cargo_colour())
+            .status()
+            .map_err(PathError::cargo)
+    }
+
+    // Tests the sandbox with only the failures to show, leaving out the
+    // compiler's warnings on what the koans leave unused.
+    pub fn test_focused(&self) -> Result<ExitStatus, PathError> {
+        self.focused(output::cargo_colour()).status().map_err(PathError::cargo)
+    }
+
+    // Tests the sandbox as test_focused does, keeping what was said for the
+    // runner to pass on.
+    pub fn test_focused_quietly(&self) -> Result<Output, PathError> {
+        self.focused(output::captured_cargo_colour())
+            .output()
+            .map_err(PathError::cargo)
+    }
+
+    fn focused(&self, colour: &str) -> Command {
+        let mut command = self.cargo_test();
+        command
+            .arg("--color")
+            .arg(colour)
+            .env("RUSTFLAGS", "-A warnings")
+            .env("RUST_BACKTRACE", "0");
+        command
+    }
+
+    // Tests the sandbox, keeping what cargo and each koan said, with every
+    // koan named as it runs.
+    pub fn test_verbosely(&self) -> Result<Output, PathError> {
+        Command::new("cargo")
+            .arg("test")
+            .arg("--color")
+            .arg("never")
+            .arg("--manifest-path")
+            .arg(self.dir.join("Cargo.toml"))
+            .env("RUST_BACKTRACE", "0")
+            .output()
+            .map_err(PathError::cargo)
+    }
+
+    // Tests the sandbox without letting cargo or the koans speak.
+    pub fn test_quietly(&self) -> Result<ExitStatus, PathError> {
+        self.cargo_test()
+            .output()
+            .map(|output| output.status)
+            .map_err(PathError::cargo)
+    }
+
+    // Runs clippy over the koans in the sandbox, keeping what it says. Every
+    // lint is only advice, and the koans leave values unused to make their
+    // point, so that is not advised on at all.
+    pub fn clippy(&self) -> Result<(bool, String), PathError> {
+        let output = Command::new("cargo")
+            .arg("clippy")
+            .arg("-q")
+            .arg("--tests")
+            .arg("--color")
+            .arg("never")
+            .arg("--manifest-path")
+            .arg(self.dir.join("Cargo.toml"))
+            .arg("--")
+            .arg("--cap-lints")
+            .arg("warn")
+            .arg("-A")
+            .arg("unused")
+            .output()
+            .map_err(PathError::cargo)?;
+        Ok((output.status.success(), String::from_utf8_lossy(&output.stderr).into_owned()))
+    }
+
+    fn cargo_test(&self) -> Command {
+        let mut command = Command::new("cargo");
+        command
+            .arg("test")
+            .arg("-q")
+            .arg("--manifest-path")
+            .arg(self.dir.join("Cargo.toml"));
+        command
+    }
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::Path;
+
+use error::PathError;
+use module;
+use paths;
+
+const KOAN: &str = "\
+// summary: What the {name} koans teach, shown when the learner completes them.
+// reflect: A question for the learner to consider once they have.
+
+// What this koan teaches, and how. Every koan is introduced by a comment.
+#[test]
+fn {name}() {
+    koan_assert!(\"The lesson this koan teaches.\", {answer});
+}
+";
+
+// Why a name cannot be given to a new module of koans, if it cannot. The
+// name becomes a file under src/koans/ that koan! includes, and the name of
+// its first test, so it must be a plain Rust identifier.
+pub fn unfit(name: &str, path: &[String]) -> Option<String> {
+    let mut chars = name.chars();
+    let plain = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if !plain {
+        Some(text!("not-a-koan-name", name = name))
+    } else if module::is_keyword(name) {
+        Some(text!("keyword-koan-name", name = name))
+    } else if path.iter().any(|koan| koan == name)
+        || paths::koan(name).exists()
+        || paths::solution(name).exists()
+    {
+        Some(text!("koan-exists", name = name))
+    } else {
+        None
+    }
+}
+
+// Writes a new module of koans and its solution from the template, and
+// places it on the path at the given position.
+pub fn new_koan(name: &str, path: &[String], position: usize) -> Result<(), PathError> {
+    let (koan, solution) = from_template(name);
+    write(&paths::koan(name), &koan)?;
+    write(&paths::solution(name), &solution)?;
+
+    let mut path = path.to_vec();
+    path.insert(position, name.to_string());
+    write(&paths::manifest(), &lines(&path))?;
+
+    // The project's own tests walk the solutions in the order of the path
+    let test = paths::solutions_test();
+    let source = fs::read_to_string(&test)
+        .map_err(|error| PathError::io(&format!("read {}", test.display()), error))?;
+    let mut walked = String::new();
+    for line in source.lines() {
+        if line.starts_with("koan!(") {
+            continue;
+        }
+        walked.push_str(line);
+        walked.push('\n');
+    }
+    for koan in &path {
+        walked.push_str(&format!("koan!({}, \"{}\");\n", module::ident(koan), koan));
+    }
+    write(&test, &walked)
+}
+
+// A new module of koans and its solution, which fills in the koan's blank
+// and nothing else.
+fn from_template(name: &str) -> (String, String) {
+    let template = KOAN.replace("{name}", name);
+    (template.replace("{answer}", "__"), template.replace("{answer}", "true"))
+}
+
+fn lines(path: &[String]) -> String {
+    path.iter().map(|koan| format!("{}\n", koan)).collect()
+}
+
+fn write(file: &Path, contents: &str) -> Result<(), PathError> {
+    fs::write(file, contents).map_err(|error| PathError::io(&format!("write {}", file.display()), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_solution_differs_from_the_koan_only_in_its_blank() {
+        let (koan, solution) = from_template("zen__ways");
+        assert!(koan.contains("fn zen__ways()"));
+        assert!(koan.contains("teaches.\", __);"));
+        assert!(solution.contains("fn zen__ways()"));
+        assert!(solution.contains("the zen__ways koans"));
+        assert_eq!(koan.replace("teaches.\", __);", "teaches.\", true);"), solution);
+    }
+}
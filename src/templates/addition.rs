@@ -0,0 +1,8 @@
+// vary: a 2..=60
+// vary: b 2..=40
+
+// The + operator adds two numbers together, whichever two they are.
+#[test]
+fn addition() {
+    koan_assert_eq!(__, $a + $b, "Adding is the same whatever the numbers.")
+}
@@ -0,0 +1,36 @@
+// vary: n 1..=200
+// vary: m 2..=9
+// vary: small -100..=-1
+
+// Unsigned integers can be reduced only as far as their minimum value of 0
+#[test]
+fn sub_unsigned_int() {
+    let mut num: u8 = $n;
+    num -= __;
+    koan_assert!("An unsigned integer can be reduced only as far as zero.", num == u8::MIN);
+}
+
+// Signed integers can be reduced below zero
+#[test]
+fn sub_signed_int() {
+    let mut num: i8 = 0;
+    num += $small;
+    koan_assert!("A signed integer can go below zero.", num == __);
+}
+
+// Multiplication works much as you would expect, so long as the result fits
+#[test]
+fn multiply_numbers() {
+    let num: i32 = $n;
+    koan_assert!("Multiplying works as you would expect.", num * $m == __);
+}
+
+// Integer division rounds towards zero, and % gives the remainder
+#[test]
+fn divide_numbers() {
+    let num: u32 = $n;
+    koan_assert!(
+        "Integer division rounds towards zero, and % gives the remainder.",
+        num / $m == __ && num % $m == __
+    );
+}
@@ -0,0 +1,33 @@
+// vary: word lotus, bamboo, river, mountain, lantern, pebble, willow
+// vary: other temple, cloud, tea, moon, garden
+// vary: times 2..=5
+
+// String slices can be compared with a literal of the same text
+#[test]
+fn string_literals() {
+    let string_slice = "$word";
+    koan_assert!("A string slice is equal to a literal of the same text.", string_slice == __);
+}
+
+// Strings can be built up with push_str
+#[test]
+fn growable_strings() {
+    let mut string = String::new();
+    string.push_str("$word");
+    string.push_str(" $other");
+    koan_assert!("push_str adds to the end of a String.", string == __);
+}
+
+// The length of a string is counted in bytes
+#[test]
+fn string_length() {
+    let word = String::from("$word");
+    koan_assert_eq!(__, word.len(), "A string's length is counted in bytes.");
+}
+
+// repeat joins copies of a string end to end
+#[test]
+fn string_repeat() {
+    let echo = "$other".repeat($times);
+    koan_assert_eq!(__, echo.len(), "repeat joins copies of a string end to end.");
+}
@@ -0,0 +1,253 @@
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use achievements;
+use error::PathError;
+use koan::{self, Koan};
+use locale;
+use paths;
+use progress::Progress;
+
+// How often the koans are checked for changes.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+// A terminal front end to the path: the koans in order with how far the
+// learner has come, the koan at hand, and what cargo said of the last walk.
+// The path is walked again whenever a koan file is saved.
+pub fn run() -> Result<(), PathError> {
+    let mut terminal = ratatui::try_init().map_err(|error| PathError::io("prepare the terminal", error))?;
+    let result = Tui::new().and_then(|mut tui| tui.run(&mut terminal));
+    ratatui::restore();
+    result
+}
+
+struct Tui {
+    modules: Vec<String>,
+    unlocked: Vec<String>,
+    completed: Vec<String>,
+    list: ListState,
+    // What cargo said on the last walk, and whether the path was clear
+    said: String,
+    passed: bool,
+    status: String,
+    scroll: u16,
+    last_saved: Option<SystemTime>,
+}
+
+impl Tui {
+    fn new() -> Result<Tui, PathError> {
+        let mut tui = Tui {
+            modules: ::the_path()?,
+            unlocked: Vec::new(),
+            completed: Vec::new(),
+            list: ListState::default(),
+            said: String::new(),
+            passed: false,
+            status: String::new(),
+            scroll: 0,
+            last_saved: last_saved(),
+        };
+        tui.refresh(&Progress::load()?)?;
+        Ok(tui)
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), PathError> {
+        self.walk(terminal)?;
+
+        loop {
+            self.draw(terminal)?;
+
+            let ready = event::poll(WATCH_INTERVAL).map_err(|error| PathError::io("read the keyboard", error))?;
+            if ready {
+                let event = event::read().map_err(|error| PathError::io("read the keyboard", error))?;
+                if let Event::Key(key) = event {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Char('r') => self.walk(terminal)?,
+                        KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+                        KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+                        KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+                        KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+                        _ => {}
+                    }
+                }
+            }
+
+            let saved = last_saved();
+            if saved != self.last_saved {
+                self.last_saved = saved;
+                self.walk(terminal)?;
+            }
+        }
+    }
+
+    // Walks the path just as `cargo run` does, taking a step if it is clear.
+    fn walk(&mut self, terminal: &mut DefaultTerminal) -> Result<(), PathError> {
+        self.status = text!("meditating");
+        self.draw(terminal)?;
+
+        let mut progress = Progress::load()?;
+        let (passed, said) = ::walk_the_path_quietly()?;
+        self.status = match ::take_a_step(&mut progress, passed)?.0 {
+            ::Step::Onward(next_koan) => text!("ahead", koan = next_koan),
+            ::Step::Finished => text!("one-hand-clapping"),
+            ::Step::Blocked => text!("blocked"),
+        };
+        for milestone in achievements::award(&mut progress, &self.modules)? {
+            self.status.push(' ');
+            self.status.push_str(&text!("achievement-earned", title = milestone.title()));
+        }
+        progress.save()?;
+
+        self.passed = passed;
+        self.said = said;
+        self.scroll = 0;
+        self.refresh(&progress)
+    }
+
+    fn refresh(&mut self, progress: &Progress) -> Result<(), PathError> {
+        self.unlocked = ::unlocked_koans(progress)?;
+        self.completed = progress
+            .completed()
+            .iter()
+            .map(|entry| entry.name.clone())
+            .collect();
+        self.list.select(Some(self.unlocked.len().saturating_sub(1)));
+        Ok(())
+    }
+
+    fn draw(&mut self, terminal: &mut DefaultTerminal) -> Result<(), PathError> {
+        let focus = self.focus()?;
+        terminal
+            .draw(|frame| self.render(frame, &focus))
+            .map(|_| ())
+            .map_err(|error| PathError::io("draw the path", error))
+    }
+
+    // The koan to show for the selected module.
+    fn focus(&self) -> Result<Option<Koan>, PathError> {
+        let module = match self.selected() {
+            Some(module) if self.unlocked.contains(module) => module,
+            _ => return Ok(None),
+        };
+
+        let koans = koan::koans_in(&koan::read_source(module)?);
+        Ok(koan::at_hand(koans, &self.said, module))
+    }
+
+    fn selected(&self) -> Option<&String> {
+        self.list.selected().and_then(|index| self.modules.get(index))
+    }
+
+    fn render(&mut self, frame: &mut Frame, focus: &Option<Koan>) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(frame.area());
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(20), Constraint::Min(1)])
+            .split(rows[0]);
+        let panes = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(columns[1]);
+
+        let current = self.unlocked.last().cloned();
+        let items: Vec<ListItem> = self
+            .modules
+            .iter()
+            .map(|module| {
+                let (mark, style) = if self.completed.contains(module) {
+                    ("✓ ", Style::default().fg(Color::Green))
+                } else if Some(module) == current.as_ref() {
+                    ("▸ ", Style::default().fg(Color::Yellow))
+                } else {
+                    ("· ", Style::default().add_modifier(Modifier::DIM))
+                };
+                ListItem::new(format!("{}{}", mark, module)).style(style)
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(" The Path "))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, columns[0], &mut self.list);
+
+        let module = self.selected().cloned().unwrap_or_default();
+        let (title, koan) = match *focus {
+            Some(ref koan) => (format!(" {} ", koan.name), koan_text(&module, koan)),
+            None => (" Koan ".to_string(), Text::from(text!("not-on-path"))),
+        };
+        let koan = Paragraph::new(koan)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(koan, panes[0]);
+
+        let colour = if self.passed { Color::Green } else { Color::Red };
+        let said = Paragraph::new(self.said.as_str())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colour))
+                    .title(" cargo test "),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        frame.render_widget(said, panes[1]);
+
+        let footer = Line::from(vec![
+            Span::raw(self.status.clone()),
+            Span::styled(
+                format!("  {}", text!("tui-keys")),
+                Style::default().add_modifier(Modifier::DIM),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(footer), rows[1]);
+    }
+}
+
+// A koan's explanation and source, with its blanks picked out.
+fn koan_text(module: &str, koan: &Koan) -> Text<'static> {
+    let explanation = Style::default().add_modifier(Modifier::DIM | Modifier::ITALIC);
+    let blank = Style::default().fg(Color::Black).bg(Color::Yellow);
+
+    let mut lines: Vec<Line> = locale::comment(module, koan)
+        .lines()
+        .map(|line| Line::styled(line.to_string(), explanation))
+        .collect();
+    if !lines.is_empty() {
+        lines.push(Line::default());
+    }
+
+    for line in koan.source.lines() {
+        let mut spans = Vec::new();
+        for (index, piece) in line.split("__").enumerate() {
+            if index > 0 {
+                spans.push(Span::styled("__", blank));
+            }
+            spans.push(Span::raw(piece.to_string()));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    Text::from(lines)
+}
+
+// When a koan file was last saved.
+fn last_saved() -> Option<SystemTime> {
+    let koans = fs::read_dir(paths::koan("").parent()?).ok()?;
+    koans
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().and_then(|metadata| metadata.modified()).ok())
+        .max()
+}
@@ -0,0 +1,109 @@
+// Fills in the templates of practice koans, which vary with a seed. This is
+// shared with build.rs, which fills them in for the learner.
+//
+// A template declares what varies in tagged comments, either a range of
+// numbers or a list of choices:
+//     // vary: a 1..=50
+//     // vary: word lotus, bamboo, river
+// and uses each in its code as $a or $word.
+
+const VARY: &str = "// vary:";
+
+pub fn fill(template: &str, seed: u64) -> String {
+    let mut random = Random(seed ^ hash(template));
+    let mut values: Vec<(String, String)> = Vec::new();
+    let mut filled = String::new();
+
+    for line in template.lines() {
+        if let Some(spec) = line.trim().strip_prefix(VARY) {
+            if let Some((name, choices)) = spec.trim().split_once(' ') {
+                values.push((name.to_string(), random.choose(choices.trim())));
+            }
+            continue;
+        }
+        filled.push_str(&substitute(line, &values));
+        filled.push('\n');
+    }
+
+    filled.trim_start().to_string()
+}
+
+// Replaces each $name in a line with its value. Names run as far as the
+// identifier does, so $a is not mistaken for part of $ab.
+fn substitute(line: &str, values: &[(String, String)]) -> String {
+    let mut substituted = String::new();
+    let mut rest = line;
+    while let Some(start) = rest.find('$') {
+        substituted.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let length = after
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(after.len());
+        match values.iter().find(|(name, _)| *name == after[..length]) {
+            Some((_, value)) => substituted.push_str(value),
+            None => substituted.push_str(&rest[start..start + 1 + length]),
+        }
+        rest = &after[length..];
+    }
+    substituted.push_str(rest);
+    substituted
+}
+
+fn hash(text: &str) -> u64 {
+    // FNV-1a, which is enough to tell one template from another
+    text.bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+// splitmix64, a small generator that needs nothing outside the standard
+// library and is well behaved for any seed, zero included.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // A value from a range such as 1..=50, or one of a list of choices.
+    fn choose(&mut self, choices: &str) -> String {
+        if let Some((low, high)) = choices.split_once("..=") {
+            if let (Ok(low), Ok(high)) = (low.trim().parse::<i64>(), high.trim().parse::<i64>()) {
+                let span = (high - low + 1).max(1) as u64;
+                return (low + (self.next() % span) as i64).to_string();
+            }
+        }
+        let choices: Vec<&str> = choices.split(',').map(|choice| choice.trim()).collect();
+        choices[(self.next() % choices.len() as u64) as usize].to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_replaces_each_name_with_its_value() {
+        let template = "\
+// vary: word lotus
+// vary: n 7..=7
+
+fn $word() -> u8 {
+    $n + $nn + $word_count
+}
+";
+        assert_eq!(fill(template, 1), "fn lotus() -> u8 {\n    7 + $nn + $word_count\n}\n");
+    }
+
+    #[test]
+    fn fill_stays_within_its_range_for_any_seed() {
+        for seed in 0..100 {
+            let n: i32 = fill("// vary: n -3..=3\n$n", seed).trim().parse().unwrap();
+            assert!((-3..=3).contains(&n), "{} from seed {}", n, seed);
+        }
+        assert_eq!(fill("// vary: n 1..=50\n$n", 42), fill("// vary: n 1..=50\n$n", 42));
+    }
+}
@@ -0,0 +1,55 @@
+// Compiles the runner's modules on their own, so that the tests each keeps
+// of its parsers are run: they read koan files, cargo's output and the
+// learner's records, and a change that misreads them is caught here rather
+// than by a learner. The runner's own test build is the learner's path, which
+// leaves the modules out.
+#![allow(dead_code)]
+
+// Speaks a message from the catalogue, as the runner's own text! does.
+macro_rules! text {
+    ($key:expr) => {
+        ::locale::text($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+) => {
+        ::locale::text($key, &[$((stringify!($name), &$value[..])),+])
+    };
+}
+
+#[path = "../src/error.rs"]
+mod error;
+
+#[path = "../src/explain.rs"]
+mod explain;
+
+#[path = "../src/koan.rs"]
+mod koan;
+
+#[path = "../src/locale.rs"]
+mod locale;
+
+#[path = "../src/module.rs"]
+mod module;
+
+#[path = "../src/output.rs"]
+mod output;
+
+#[path = "../src/paths.rs"]
+mod paths;
+
+#[path = "../src/progress.rs"]
+mod progress;
+
+#[path = "../src/report.rs"]
+mod report;
+
+#[path = "../src/results.rs"]
+mod results;
+
+#[path = "../src/sandbox.rs"]
+mod sandbox;
+
+#[path = "../src/scaffold.rs"]
+mod scaffold;
+
+#[path = "../src/vary.rs"]
+mod vary;
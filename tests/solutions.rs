@@ -0,0 +1,29 @@
+// Walks the whole path with the reference solutions, so that a koan which can
+// no longer be solved is caught here rather than by a learner.
+#![allow(dead_code, unused_variables, unused_mut, clippy::all)]
+
+#[path = "../src/harness.rs"]
+#[macro_use]
+mod harness;
+
+// Walks a solution in a module of its own, as the runner walks each koan
+// file, such as `koan!(vec, "vec")`.
+macro_rules! koan {
+    ($module:ident, $name:expr) => (
+        mod $module {
+            include!(concat!("../solutions/", $name, ".rs"));
+        }
+    );
+}
+
+koan!(the_truth, "the_truth");
+koan!(addition, "addition");
+koan!(boolean, "boolean");
+koan!(array, "array");
+koan!(integer, "integer");
+koan!(vec, "vec");
+koan!(string, "string");
+koan!(hash_map, "hash_map");
+koan!(r#struct, "struct");
+koan!(ownership, "ownership");
+koan!(traits, "traits");